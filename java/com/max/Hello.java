@@ -3,25 +3,23 @@ package com.max;
 public class Hello {
 
     public static void main(String[] args) {
-//         int first = fib();
-//         int second = fib();
-//
-//         int res = first + second;
-        int x = 11;
-        int y = 22;
+        int first = fib();
+        int second = fib();
 
-        int z = x + y;
+        int res = first + second;
+
+        System.out.println(res);
     }
 
-//     public static int fib(){
-//         int a = 1;
-//         int b = 1;
-//         for(int i = 0; i < 10; ++i){
-//             int temp = a + b;
-//             a = b;
-//             b = temp;
-//         }
-//
-//         return a;
-//     }
+    public static int fib(){
+        int a = 1;
+        int b = 1;
+        for(int i = 0; i < 10; ++i){
+            int temp = a + b;
+            a = b;
+            b = temp;
+        }
+
+        return a;
+    }
 }
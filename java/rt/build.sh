@@ -0,0 +1,6 @@
+#!/usr/bin/env bash
+# Compiles bootstrap classes used by rust-vm instead of the real 'java.base' module
+cd "$(dirname "$0")" || exit 1
+
+find java -name '*.class' -delete
+javac --patch-module java.base=. -d . $(find java -name '*.java')
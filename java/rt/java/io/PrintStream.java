@@ -0,0 +1,95 @@
+package java.io;
+
+/**
+ * Minimal replacement of java.io.PrintStream writing directly into the file descriptor.
+ */
+public class PrintStream {
+
+    // 1 for the standard output and 2 for the standard error
+    private final int fd;
+
+    public PrintStream(int fd) {
+        this.fd = fd;
+    }
+
+    public void print(boolean b) {
+        writeBoolean(fd, b);
+    }
+
+    public void print(char c) {
+        writeChar(fd, c);
+    }
+
+    public void print(int i) {
+        writeInt(fd, i);
+    }
+
+    public void print(long l) {
+        writeLong(fd, l);
+    }
+
+    public void print(float f) {
+        writeFloat(fd, f);
+    }
+
+    public void print(double d) {
+        writeDouble(fd, d);
+    }
+
+    public void print(String s) {
+        writeString(fd, s);
+    }
+
+    public void println() {
+        writeChar(fd, '\n');
+    }
+
+    public void println(boolean x) {
+        print(x);
+        println();
+    }
+
+    public void println(char x) {
+        print(x);
+        println();
+    }
+
+    public void println(int x) {
+        print(x);
+        println();
+    }
+
+    public void println(long x) {
+        print(x);
+        println();
+    }
+
+    public void println(float x) {
+        print(x);
+        println();
+    }
+
+    public void println(double x) {
+        print(x);
+        println();
+    }
+
+    public void println(String x) {
+        print(x);
+        println();
+    }
+
+    private static native void writeBoolean(int fd, boolean b);
+
+    private static native void writeChar(int fd, char c);
+
+    private static native void writeInt(int fd, int i);
+
+    private static native void writeLong(int fd, long l);
+
+    private static native void writeFloat(int fd, float f);
+
+    private static native void writeDouble(int fd, double d);
+
+    private static native void writeString(int fd, String s);
+}
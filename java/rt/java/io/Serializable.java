@@ -0,0 +1,7 @@
+package java.io;
+
+/**
+ * Marker interface implemented by all array types.
+ */
+public interface Serializable {
+}
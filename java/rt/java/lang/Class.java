@@ -0,0 +1,36 @@
+package java.lang;
+
+/**
+ * Minimal replacement of java.lang.Class, instances are created by rust-vm only,
+ * so there is exactly one mirror for every loaded class or array type.
+ */
+public final class Class<T> {
+
+    // binary name, like 'java.lang.String' or '[Ljava.lang.String;', set by rust-vm
+    private final String name;
+
+    // id of the defining loader, loader of the element class is used for array types, set by rust-vm
+    private final int classLoaderId;
+
+    private Class() {
+        this.name = null;
+        this.classLoaderId = 0;
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    public boolean isArray() {
+        return name.charAt(0) == '[';
+    }
+
+    // 'null' is returned for classes defined by the bootstrap loader
+    public native ClassLoader getClassLoader();
+
+    /**
+     * Creates instance of the class using its no-arg constructor, class is initialized first.
+     */
+    @Deprecated
+    public native T newInstance() throws InstantiationException, IllegalAccessException;
+}
@@ -0,0 +1,124 @@
+package java.lang;
+
+/**
+ * Minimal replacement of java.lang.ClassLoader. Class is identified by its name together with
+ * its defining loader, so different loaders can define classes with the same name.
+ * Loaders delegate to their parent first, loader without parent delegates to the bootstrap loader.
+ */
+public abstract class ClassLoader {
+
+    private final ClassLoader parent;
+
+    // number identifying the loader inside rust-vm, assigned when the loader is registered
+    private final int id;
+
+    protected ClassLoader() {
+        this(getSystemClassLoader());
+    }
+
+    protected ClassLoader(ClassLoader parent) {
+        this(parent, false);
+    }
+
+    ClassLoader(ClassLoader parent, boolean system) {
+        this.parent = parent;
+        this.id = register(this, system);
+    }
+
+    public final ClassLoader getParent() {
+        return parent;
+    }
+
+    public Class<?> loadClass(String name) throws ClassNotFoundException {
+        return loadClass(name, false);
+    }
+
+    /**
+     * Returns class loaded by this loader already, otherwise asks the parent loader and
+     * finds the class by itself only if the parent can't load it.
+     */
+    protected Class<?> loadClass(String name, boolean resolve) throws ClassNotFoundException {
+        synchronized (this) {
+            Class<?> c = findLoadedClass(name);
+
+            if (c == null) {
+                try {
+                    if (parent != null) {
+                        c = parent.loadClass(name, false);
+                    } else {
+                        c = findBootstrapClass(name);
+                    }
+                } catch (ClassNotFoundException e) {
+                    // class is not found by the parent, so this loader tries to find it
+                }
+
+                if (c == null) {
+                    c = findClass(name);
+                }
+            }
+
+            return c;
+        }
+    }
+
+    protected Class<?> findClass(String name) throws ClassNotFoundException {
+        throw new ClassNotFoundException(name);
+    }
+
+    /**
+     * Creates class from the bytes of the class file, its superclass and superinterfaces
+     * are loaded by this loader.
+     */
+    protected final Class<?> defineClass(String name, byte[] b, int off, int len) throws ClassFormatError {
+        if (off < 0 || len < 0 || off > b.length - len) {
+            throw new IndexOutOfBoundsException();
+        }
+
+        return defineClass0(name, b, off, len);
+    }
+
+    @Deprecated
+    protected final Class<?> defineClass(byte[] b, int off, int len) throws ClassFormatError {
+        return defineClass(null, b, off, len);
+    }
+
+    protected final Class<?> findLoadedClass(String name) {
+        return findLoadedClass0(name);
+    }
+
+    public static native ClassLoader getSystemClassLoader();
+
+    private static native int register(ClassLoader loader, boolean system);
+
+    private native Class<?> defineClass0(String name, byte[] b, int off, int len);
+
+    private native Class<?> findLoadedClass0(String name);
+
+    // 'null' is returned if there is no such class on the bootstrap class path
+    private static native Class<?> findBootstrapClass(String name);
+
+    // 'null' is returned if there is no such class on the application class path
+    private static native Class<?> findApplicationClass(String name);
+
+    /**
+     * Loader of the classes from the application class path, created by rust-vm
+     * when it's requested for the first time.
+     */
+    private static final class AppClassLoader extends ClassLoader {
+
+        private AppClassLoader() {
+            super(null, true);
+        }
+
+        @Override
+        protected Class<?> findClass(String name) throws ClassNotFoundException {
+            Class<?> c = findApplicationClass(name);
+
+            if (c == null) {
+                throw new ClassNotFoundException(name);
+            }
+
+            return c;
+        }
+    }
+}
@@ -0,0 +1,7 @@
+package java.lang;
+
+/**
+ * Marker interface implemented by all array types.
+ */
+public interface Cloneable {
+}
@@ -0,0 +1,29 @@
+package java.lang;
+
+/**
+ * Thrown when static initializer completes abruptly with an exception that is not an Error.
+ */
+public class ExceptionInInitializerError extends LinkageError {
+
+    private Throwable exception;
+
+    public ExceptionInInitializerError() {
+    }
+
+    public ExceptionInInitializerError(String message) {
+        super(message);
+    }
+
+    public ExceptionInInitializerError(Throwable thrown) {
+        this.exception = thrown;
+    }
+
+    public Throwable getException() {
+        return exception;
+    }
+
+    @Override
+    public Throwable getCause() {
+        return exception;
+    }
+}
@@ -0,0 +1,11 @@
+package java.lang;
+
+public class InstantiationException extends ReflectiveOperationException {
+
+    public InstantiationException() {
+    }
+
+    public InstantiationException(String message) {
+        super(message);
+    }
+}
@@ -0,0 +1,11 @@
+package java.lang;
+
+public class LinkageError extends Error {
+
+    public LinkageError() {
+    }
+
+    public LinkageError(String message) {
+        super(message);
+    }
+}
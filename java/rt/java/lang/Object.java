@@ -0,0 +1,42 @@
+package java.lang;
+
+/**
+ * Minimal replacement of java.lang.Object used as the root of all classes executed by rust-vm.
+ */
+public class Object {
+
+    public Object() {
+    }
+
+    public final native Class<?> getClass();
+
+    public boolean equals(Object obj) {
+        return this == obj;
+    }
+
+    public final native void notify();
+
+    public final native void notifyAll();
+
+    public final void wait() throws InterruptedException {
+        wait(0L);
+    }
+
+    public final native void wait(long timeoutMillis) throws InterruptedException;
+
+    public final void wait(long timeoutMillis, int nanos) throws InterruptedException {
+        if (timeoutMillis < 0) {
+            throw new IllegalArgumentException("timeout value is negative");
+        }
+
+        if (nanos < 0 || nanos > 999999) {
+            throw new IllegalArgumentException("nanosecond timeout value out of range");
+        }
+
+        if (nanos > 0 && timeoutMillis < Long.MAX_VALUE) {
+            timeoutMillis++;
+        }
+
+        wait(timeoutMillis);
+    }
+}
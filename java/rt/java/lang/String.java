@@ -0,0 +1,68 @@
+package java.lang;
+
+/**
+ * Minimal replacement of java.lang.String keeping characters as UTF-16 code units.
+ */
+public final class String {
+
+    private final char[] value;
+
+    public String(char[] value) {
+        char[] copy = new char[value.length];
+        for (int i = 0; i < value.length; ++i) {
+            copy[i] = value[i];
+        }
+        this.value = copy;
+    }
+
+    public int length() {
+        return value.length;
+    }
+
+    public char charAt(int index) {
+        return value[index];
+    }
+
+    public boolean equals(Object anObject) {
+        if (this == anObject) {
+            return true;
+        }
+
+        if (!(anObject instanceof String)) {
+            return false;
+        }
+
+        String other = (String) anObject;
+        if (other.value.length != value.length) {
+            return false;
+        }
+
+        for (int i = 0; i < value.length; ++i) {
+            if (value[i] != other.value[i]) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    /**
+     * Same hash as the real java.lang.String, it's used to compile 'switch' over strings.
+     */
+    public int hashCode() {
+        int h = 0;
+        for (int i = 0; i < value.length; ++i) {
+            h = 31 * h + value[i];
+        }
+        return h;
+    }
+
+    /**
+     * Returns canonical representation of the string from the pool shared with string literals.
+     */
+    public native String intern();
+
+    public String toString() {
+        return this;
+    }
+}
@@ -0,0 +1,21 @@
+package java.lang;
+
+import java.io.PrintStream;
+
+/**
+ * Minimal replacement of java.lang.System with the standard output and error streams.
+ */
+public final class System {
+
+    public static final PrintStream out = new PrintStream(1);
+
+    public static final PrintStream err = new PrintStream(2);
+
+    private System() {
+    }
+
+    /**
+     * Runs the garbage collector.
+     */
+    public static native void gc();
+}
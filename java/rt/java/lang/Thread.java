@@ -0,0 +1,163 @@
+package java.lang;
+
+/**
+ * Minimal replacement of java.lang.Thread, every started thread is executed by rust-vm
+ * on a separate OS thread.
+ */
+public class Thread implements Runnable {
+
+    private static int threadInitNumber;
+
+    private static long threadSeqNumber;
+
+    private String name;
+
+    // thread id, also used by rust-vm to find the running thread
+    private final long tid;
+
+    private boolean daemon;
+
+    private boolean started;
+
+    // interrupt status, read by rust-vm when the thread blocks in sleep, wait or join
+    private volatile boolean interrupted;
+
+    private final Runnable target;
+
+    public Thread() {
+        this(null, null);
+    }
+
+    public Thread(Runnable target) {
+        this(target, null);
+    }
+
+    public Thread(String name) {
+        this(null, name);
+    }
+
+    public Thread(Runnable target, String name) {
+        this.target = target;
+        this.name = (name != null) ? name : genThreadName();
+        this.tid = nextThreadID();
+
+        // new thread is daemon if it's created by daemon thread
+        Thread parent = currentThread();
+        this.daemon = parent != null && parent.daemon;
+    }
+
+    private static synchronized long nextThreadID() {
+        return ++threadSeqNumber;
+    }
+
+    /**
+     * Generates names like 'Thread-0', string concatenation is not used as it requires
+     * 'invokedynamic' support.
+     */
+    private static synchronized String genThreadName() {
+        int number = threadInitNumber++;
+
+        int digitsCount = 1;
+        for (int rest = number / 10; rest > 0; rest /= 10) {
+            ++digitsCount;
+        }
+
+        char[] chars = new char[7 + digitsCount];
+        chars[0] = 'T';
+        chars[1] = 'h';
+        chars[2] = 'r';
+        chars[3] = 'e';
+        chars[4] = 'a';
+        chars[5] = 'd';
+        chars[6] = '-';
+
+        for (int i = chars.length - 1; i >= 7; --i) {
+            chars[i] = (char) ('0' + number % 10);
+            number /= 10;
+        }
+
+        return new String(chars);
+    }
+
+    public static native Thread currentThread();
+
+    public static native void sleep(long millis) throws InterruptedException;
+
+    public static native void yield();
+
+    public synchronized void start() {
+        if (started) {
+            throw new IllegalThreadStateException();
+        }
+        started = true;
+        start0();
+    }
+
+    private native void start0();
+
+    public void run() {
+        if (target != null) {
+            target.run();
+        }
+    }
+
+    public final native boolean isAlive();
+
+    public final void join() throws InterruptedException {
+        join(0);
+    }
+
+    public final void join(long millis) throws InterruptedException {
+        if (millis < 0) {
+            throw new IllegalArgumentException("timeout value is negative");
+        }
+        join0(millis);
+    }
+
+    private native void join0(long millis) throws InterruptedException;
+
+    public void interrupt() {
+        interrupted = true;
+        interrupt0();
+    }
+
+    // wakes up the thread if it's blocked
+    private native void interrupt0();
+
+    public boolean isInterrupted() {
+        return interrupted;
+    }
+
+    public static boolean interrupted() {
+        Thread thread = currentThread();
+        boolean wasInterrupted = thread.interrupted;
+        thread.interrupted = false;
+        return wasInterrupted;
+    }
+
+    public final void setDaemon(boolean on) {
+        if (isAlive()) {
+            throw new IllegalThreadStateException();
+        }
+        daemon = on;
+    }
+
+    public final boolean isDaemon() {
+        return daemon;
+    }
+
+    public final void setName(String name) {
+        if (name == null) {
+            throw new NullPointerException("name cannot be null");
+        }
+        this.name = name;
+    }
+
+    public final String getName() {
+        return name;
+    }
+
+    public long getId() {
+        return tid;
+    }
+}
@@ -0,0 +1,36 @@
+package java.lang;
+
+/**
+ * Minimal replacement of java.lang.Throwable, the root of all exceptions thrown by 'athrow'
+ * or raised by rust-vm itself.
+ */
+public class Throwable {
+
+    private String detailMessage;
+
+    private Throwable cause;
+
+    public Throwable() {
+    }
+
+    public Throwable(String message) {
+        this.detailMessage = message;
+    }
+
+    public Throwable(String message, Throwable cause) {
+        this.detailMessage = message;
+        this.cause = cause;
+    }
+
+    public Throwable(Throwable cause) {
+        this.cause = cause;
+    }
+
+    public String getMessage() {
+        return detailMessage;
+    }
+
+    public Throwable getCause() {
+        return cause;
+    }
+}
@@ -0,0 +1,40 @@
+package java.lang.invoke;
+
+/**
+ * Minimal replacement of java.lang.invoke.MethodHandle, instances are created by rust-vm
+ * while resolving CONSTANT_MethodHandle entries. Handles can't be invoked yet.
+ */
+public final class MethodHandle {
+
+    // one of REF_getField .. REF_invokeInterface kinds, set by rust-vm
+    private final int referenceKind;
+
+    private final Class<?> referenceClass;
+
+    private final String referenceName;
+
+    private final MethodType type;
+
+    private MethodHandle() {
+        this.referenceKind = 0;
+        this.referenceClass = null;
+        this.referenceName = null;
+        this.type = null;
+    }
+
+    public MethodType type() {
+        return type;
+    }
+
+    public int getReferenceKind() {
+        return referenceKind;
+    }
+
+    public Class<?> getReferenceClass() {
+        return referenceClass;
+    }
+
+    public String getName() {
+        return referenceName;
+    }
+}
@@ -0,0 +1,19 @@
+package java.lang.invoke;
+
+/**
+ * Minimal replacement of java.lang.invoke.MethodType, instances are created by rust-vm
+ * while resolving CONSTANT_MethodType and CONSTANT_MethodHandle entries.
+ */
+public final class MethodType {
+
+    // method descriptor, like '(ILjava/lang/String;)V', set by rust-vm
+    private final String descriptor;
+
+    private MethodType() {
+        this.descriptor = null;
+    }
+
+    public String toMethodDescriptorString() {
+        return descriptor;
+    }
+}
@@ -1,6 +1,6 @@
-use std::io::Error;
+use std::io::{Error, ErrorKind};
 
-use crate::class_loader::constant_pool::ConstantPool;
+use crate::class_loader::constant_pool::{ConstantPool, ConstantType};
 use crate::class_loader::raw_data::RawByteBuffer;
 
 /*
@@ -15,6 +15,10 @@ pub enum AttributeInfo {
         max_locals: u16,
         exception_table: Vec<ExceptionTableInfo>,
     },
+    ConstantValue {
+        name: String,
+        constant_value_index: u16,
+    },
     NotParsedYet,
 }
 
@@ -40,12 +44,8 @@ impl AttributeInfo {
 
             let bytecode_length = data.read_4_bytes()?;
 
-            let mut bytecode = Vec::with_capacity(bytecode_length as usize);
-
             // Fully read all opcodes representing function 'code' body
-            for _ in 0..bytecode_length {
-                bytecode.push(data.read_1_byte()?);
-            }
+            let bytecode = data.read_bytes(bytecode_length as usize)?;
 
             let exception_table_length = data.read_2_bytes()?;
 
@@ -60,7 +60,7 @@ impl AttributeInfo {
             let code_attributes_count = data.read_2_bytes()?;
             // read all code attributed here if any
             for _ in 0..code_attributes_count {
-                let _cur_attribute = AttributeInfo::from(data, constant_pool);
+                AttributeInfo::from(data, constant_pool)?;
             }
 
             Ok(AttributeInfo::Code {
@@ -70,6 +70,36 @@ impl AttributeInfo {
                 max_locals,
                 exception_table,
             })
+        } else if attr_name == "ConstantValue" {
+            /*
+            4.7.2. The ConstantValue Attribute
+            https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.2
+             */
+            let constant_value_index = data.read_2_bytes()?;
+
+            let constant_value = constant_pool.get_constant(constant_value_index as usize)?;
+
+            if !matches!(
+                constant_value,
+                ConstantType::Integer { .. }
+                    | ConstantType::Float { .. }
+                    | ConstantType::Long { .. }
+                    | ConstantType::Double { .. }
+                    | ConstantType::String { .. }
+            ) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Invalid ConstantValue attribute at constant_pool index {}",
+                        constant_value_index
+                    ),
+                ));
+            }
+
+            Ok(AttributeInfo::ConstantValue {
+                name: attr_name,
+                constant_value_index,
+            })
         } else {
             // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.12
 
@@ -107,5 +137,22 @@ impl ExceptionTableInfo {
             catch_type,
         })
     }
-}
 
+    /**
+     * Checks if instruction at 'pc' is protected by the handler, 'end_pc' is exclusive.
+     */
+    pub fn covers(&self, pc: usize) -> bool {
+        (self.start_pc as usize) <= pc && pc < (self.end_pc as usize)
+    }
+
+    pub fn get_handler_pc(&self) -> usize {
+        self.handler_pc as usize
+    }
+
+    /**
+     * Constant pool index of the caught exception class, zero for handlers catching everything.
+     */
+    pub fn get_catch_type(&self) -> u16 {
+        self.catch_type
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use std::io::Error;
+use std::io::{Error, ErrorKind};
 
 use crate::class_loader::constant_pool::ConstantPool;
 use crate::class_loader::field_info::FieldInfo;
@@ -18,7 +18,8 @@ pub struct ClassFile {
     constant_pool: ConstantPool,
     access_flags: Vec<ClassAccessFlag>,
     this_class: String,
-    super_class: String,
+    // 'None' only for java/lang/Object
+    super_class: Option<String>,
     interfaces: Vec<u16>,
     fields: Vec<FieldInfo>,
     methods: Vec<MethodInfo>,
@@ -27,7 +28,12 @@ pub struct ClassFile {
 impl ClassFile {
     pub fn new(data: &mut RawByteBuffer) -> Result<Self, Error> {
         let magic_number = data.read_4_bytes()?;
-        assert_eq!(JAVA_MAGIC_NUMBER, magic_number);
+        if magic_number != JAVA_MAGIC_NUMBER {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Incompatible magic value {:#X}", magic_number),
+            ));
+        }
 
         let java_version = Self::read_java_version(data)?;
 
@@ -37,7 +43,7 @@ impl ClassFile {
 
         let this_class_name = Self::read_class_name(data, &constant_pool)?;
 
-        let super_class_name = Self::read_class_name(data, &constant_pool)?;
+        let super_class_name = Self::read_super_class_name(data, &constant_pool)?;
 
         let interfaces = Self::read_interfaces(data)?;
 
@@ -72,6 +78,20 @@ impl ClassFile {
         constant_pool.resolve_constant_pool_utf((this_class) as usize)
     }
 
+    fn read_super_class_name(
+        data: &mut RawByteBuffer,
+        constant_pool: &ConstantPool,
+    ) -> Result<Option<String>, Error> {
+        let super_class = data.read_2_bytes()?;
+
+        // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.1-200-E.1
+        if super_class == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(constant_pool.resolve_constant_pool_utf(super_class as usize)?))
+    }
+
     fn read_interfaces(data: &mut RawByteBuffer) -> Result<Vec<u16>, Error> {
         let interfaces_count = data.read_2_bytes()?;
 
@@ -117,10 +137,10 @@ impl ClassFile {
         Ok(methods)
     }
 
-    pub fn main_method(&self) -> Result<&MethodInfo, Error> {
-        for single_mehtod in &self.methods {
+    pub fn main_method_index(&self) -> Result<usize, Error> {
+        for (index, single_mehtod) in self.methods.iter().enumerate() {
             if single_mehtod.is_main() {
-                return Ok(single_mehtod);
+                return Ok(index);
             }
         }
 
@@ -130,6 +150,80 @@ impl ClassFile {
         ))
     }
 
+    /**
+     * Returns index of the method declared in this class with exactly the same name and descriptor.
+     */
+    pub fn find_method(&self, name: &str, descriptor: &str) -> Option<usize> {
+        self.methods.iter().position(|single_method| {
+            single_method.get_name() == name && single_method.get_descriptor() == descriptor
+        })
+    }
+
+    pub fn get_method(&self, index: usize) -> &MethodInfo {
+        &self.methods[index]
+    }
+
+    pub fn get_class_name(&self) -> &str {
+        &self.this_class
+    }
+
+    pub fn get_super_class_name(&self) -> Option<&str> {
+        self.super_class.as_deref()
+    }
+
+    /**
+     * Resolves names of direct superinterfaces in the order of declaration.
+     */
+    pub fn get_interface_names(&self) -> Result<Vec<String>, Error> {
+        self.interfaces
+            .iter()
+            .map(|index| self.constant_pool.resolve_constant_pool_utf(*index as usize))
+            .collect()
+    }
+
+    pub fn get_methods(&self) -> &[MethodInfo] {
+        &self.methods
+    }
+
+    /**
+     * Returns index of the field declared in this class with exactly the same name and descriptor.
+     */
+    pub fn find_field(&self, name: &str, descriptor: &str) -> Option<usize> {
+        self.fields.iter().position(|single_field| {
+            single_field.get_name() == name && single_field.get_descriptor() == descriptor
+        })
+    }
+
+    pub fn get_field(&self, index: usize) -> &FieldInfo {
+        &self.fields[index]
+    }
+
+    pub fn get_fields(&self) -> &[FieldInfo] {
+        &self.fields
+    }
+
+    pub fn is_interface(&self) -> bool {
+        self.access_flags.contains(&ClassAccessFlag::Interface)
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.access_flags.contains(&ClassAccessFlag::Abstract)
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.access_flags.contains(&ClassAccessFlag::Final)
+    }
+
+    /**
+     * Package name in internal form, like 'com/max', empty for the unnamed package.
+     */
+    pub fn get_package_name(&self) -> &str {
+        match self.this_class.rfind('/') {
+            Some(last_separator) => &self.this_class[..last_separator],
+            None => "",
+        }
+    }
+
     pub fn get_constant_pool(&self) -> &ConstantPool {
         &self.constant_pool
     }
@@ -173,7 +267,7 @@ impl JavaVersion {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum ClassAccessFlag {
     Public = 0x0001,
     Final = 0x0010,
@@ -211,3 +305,92 @@ impl ClassAccessFlag {
         access_flags
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OBJECT_CLASS: &[u8] = include_bytes!("../../java/rt/java/lang/Object.class");
+
+    fn parse(data: &[u8]) -> Result<ClassFile, Error> {
+        ClassFile::new(&mut RawByteBuffer {
+            cursor: 0,
+            data: data.to_vec(),
+        })
+    }
+
+    /**
+     * Builds class 'Foo' without superclass, members and attributes, whose 'this_class'
+     * entry refers to the constant pool slot 'name_index'.
+     */
+    fn build_class(name_index: u16) -> Vec<u8> {
+        let mut data = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 61];
+
+        // constant_pool_count, #1 = Class 'name_index', #2 = Utf8 "Foo"
+        data.extend_from_slice(&[0, 3, 7]);
+        data.extend_from_slice(&name_index.to_be_bytes());
+        data.extend_from_slice(&[1, 0, 3, b'F', b'o', b'o']);
+
+        // access_flags, this_class, super_class
+        data.extend_from_slice(&[0, 0x21, 0, 1, 0, 0]);
+
+        // interfaces_count, fields_count, methods_count, attributes_count
+        data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]);
+
+        data
+    }
+
+    #[test]
+    fn parses_valid_class() {
+        let class_file = parse(OBJECT_CLASS).unwrap();
+        assert_eq!(class_file.get_class_name(), "java/lang/Object");
+        assert_eq!(class_file.get_super_class_name(), None);
+
+        let class_file = parse(&build_class(2)).unwrap();
+        assert_eq!(class_file.get_class_name(), "Foo");
+    }
+
+    #[test]
+    fn rejects_incompatible_magic_value() {
+        let mut data = OBJECT_CLASS.to_vec();
+        data[0] = 0xCB;
+
+        let error = parse(&data).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert!(error.to_string().contains("0xCBFEBABE"));
+    }
+
+    #[test]
+    fn rejects_invalid_constant_pool_index() {
+        // out of range
+        assert_eq!(
+            parse(&build_class(7)).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        // Class entry referring to itself instead of Utf8
+        assert_eq!(
+            parse(&build_class(1)).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn corrupted_class_does_not_panic() {
+        for position in 0..OBJECT_CLASS.len() {
+            for value in [0x00, 0x01, 0x7F, 0x80, 0xFF, OBJECT_CLASS[position] ^ 0x01] {
+                let mut data = OBJECT_CLASS.to_vec();
+                data[position] = value;
+
+                let _ = parse(&data);
+            }
+        }
+
+        // trailing class attributes are not parsed, so only prefixes cut before them are rejected
+        for length in 0..OBJECT_CLASS.len() {
+            let _ = parse(&OBJECT_CLASS[..length]);
+        }
+
+        assert!(parse(&OBJECT_CLASS[..OBJECT_CLASS.len() / 2]).is_err());
+    }
+}
@@ -1,70 +1,459 @@
+use std::collections::HashMap;
 use std::env;
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Error;
-use std::io::Read;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::class_loader::class_file::ClassFile;
 use crate::class_loader::raw_data::RawByteBuffer;
+use crate::class_loader::zip_archive::ZipArchive;
+use crate::jvm::jvm_class::JvmClass;
 
+// Folder with bootstrap classes used instead of the real 'java.base' module, see 'java/rt/build.sh',
+// it's searched next to the executable and in its parent folders, like 'target/debug/../../java/rt'
+const BOOTSTRAP_CLASS_PATH_FOLDER: &str = "java/rt";
+
+// every bootstrap class path contains at least this class
+const BOOTSTRAP_MARKER_CLASS: &str = "java/lang/Object.class";
+
+// separates entries of the class path, like 'classes:lib/util.jar'
+const CLASS_PATH_SEPARATOR: char = ':';
+
+/**
+ * Runtime class table, every class is loaded once and shared by all its users.
+ * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3
+ */
 pub struct ClassRegistry {
-    pub class_path_folder: String,
+    bootstrap_class_path: ClassPathEntry,
+
+    // application class path entries in search order
+    class_path: Vec<ClassPathEntry>,
+
+    // classes keyed by binary name in internal form, like 'com/max/Hello', and loader,
+    // class is registered for its defining loader and for every initiating loader
+    classes: HashMap<(String, LoaderId), ClassState>,
+
+    // number of the last user-defined loader
+    last_user_loader_id: i32,
+}
+
+/**
+ * Class loader which defined the class, class is identified by its name together
+ * with the defining loader.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LoaderId {
+    // loads classes from the bootstrap class path
+    Bootstrap,
+
+    // loads classes from the application class path, delegating to the bootstrap loader first
+    Application,
+
+    // instance of java.lang.ClassLoader subclass created by the application, it loads classes
+    // by running its 'loadClass' method, so such classes are loaded by the engine
+    User(i32),
+}
+
+impl LoaderId {
+    /**
+     * Restores loader from the number stored by java.lang.Class and java.lang.ClassLoader instances.
+     */
+    pub fn from_id(id: i32) -> Self {
+        match id {
+            0 => LoaderId::Bootstrap,
+            1 => LoaderId::Application,
+            _ => LoaderId::User(id),
+        }
+    }
+
+    pub fn get_id(&self) -> i32 {
+        match self {
+            LoaderId::Bootstrap => 0,
+            LoaderId::Application => 1,
+            LoaderId::User(id) => *id,
+        }
+    }
+}
+
+pub enum ClassState {
+    // superclass and superinterfaces of the class are being loaded by the thread with this id
+    Loading(i64),
+
+    // class is loaded and linked, initialization state is tracked by the class itself
+    Loaded(Arc<JvmClass>),
+}
+
+/**
+ * Location searched for class files: directory with package subdirectories
+ * or JAR/ZIP archive with the same layout.
+ */
+enum ClassPathEntry {
+    Directory(PathBuf),
+    Archive(ZipArchive),
 }
 
 impl ClassRegistry {
-    pub fn new(class_path_folder: String) -> Self {
-        let mut real_classpath_folder = String::new();
+    /**
+     * Creates registry for the class path with entries separated by ':', relative entries
+     * are resolved against the current directory and empty entry means the current directory.
+     * Bootstrap classes are loaded from 'bootstrap_class_path' directory or archive,
+     * 'java/rt' folder located next to the executable is used by default.
+     */
+    pub fn new(class_path: &str, bootstrap_class_path: Option<&str>) -> Result<Self, Error> {
+        let current_dir = env::current_dir()?;
 
-        let current_dir = &env::current_dir()
-            .unwrap()
-            .into_os_string()
-            .into_string()
-            .unwrap();
+        let bootstrap_class_path = match bootstrap_class_path {
+            Some(path) => current_dir.join(path),
+            None => find_bootstrap_class_path()?,
+        };
 
-        if class_path_folder == "." {
-            real_classpath_folder.push_str(current_dir);
-        } else {
-            real_classpath_folder.push_str(current_dir);
-            real_classpath_folder.push('/');
-            real_classpath_folder.push_str(&class_path_folder);
+        let class_path = class_path
+            .split(CLASS_PATH_SEPARATOR)
+            .map(|entry| {
+                let entry = if entry.is_empty() { "." } else { entry };
+                ClassPathEntry::open(current_dir.join(entry))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            bootstrap_class_path: ClassPathEntry::open(bootstrap_class_path)?,
+            class_path,
+            classes: HashMap::new(),
+            last_user_loader_id: LoaderId::Application.get_id(),
+        })
+    }
+
+    /**
+     * Returns already loaded class or loads it together with all its superclasses and
+     * superinterfaces. Application loader delegates to the bootstrap loader first,
+     * so application can't replace bootstrap classes. User-defined loaders have no class path,
+     * so only classes loaded by them already are found. Class being loaded by another thread
+     * is reported by 'ErrorKind::WouldBlock' error, the caller waits and tries again.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3.1
+     */
+    pub fn load_class(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+        thread_id: i64,
+    ) -> Result<Arc<JvmClass>, Error> {
+        self.find_class(class_name, loader, thread_id)?
+            .ok_or_else(|| class_not_found_error(&Self::class_name_to_path(class_name)))
+    }
+
+    /**
+     * The same as 'load_class', but 'None' is returned if the class file is not found,
+     * while missing superclass is still reported as an error.
+     */
+    pub fn find_class(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+        thread_id: i64,
+    ) -> Result<Option<Arc<JvmClass>>, Error> {
+        let internal_name = Self::class_name_to_path(class_name);
+
+        let delegation_loaders = match loader {
+            LoaderId::Bootstrap => vec![LoaderId::Bootstrap],
+            LoaderId::Application => vec![LoaderId::Bootstrap, LoaderId::Application],
+            LoaderId::User(_) => vec![loader],
+        };
+
+        for &delegation_loader in &delegation_loaders {
+            match self.get_class_state(&internal_name, delegation_loader) {
+                Some(ClassState::Loaded(class)) => return Ok(Some(Arc::clone(class))),
+                Some(ClassState::Loading(loading_thread_id)) => {
+                    return Err(class_loading_error(
+                        &internal_name,
+                        *loading_thread_id,
+                        thread_id,
+                    ))
+                }
+                None => {}
+            }
         }
 
-        Self {
-            class_path_folder: real_classpath_folder,
+        for defining_loader in delegation_loaders {
+            if let Some(class_file) = self.read_class_file(&internal_name, defining_loader)? {
+                return self
+                    .define_class(class_file, defining_loader, thread_id)
+                    .map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn get_class_state(&self, class_name: &str, loader: LoaderId) -> Option<&ClassState> {
+        self.classes.get(&(class_name.to_string(), loader))
+    }
+
+    /**
+     * All classes loaded so far by every loader, every class is returned once.
+     */
+    pub fn get_loaded_classes(&self) -> impl Iterator<Item = &Arc<JvmClass>> + '_ {
+        self.classes
+            .iter()
+            .filter_map(|((_, loader), state)| match state {
+                ClassState::Loaded(class) if class.get_defining_loader() == *loader => Some(class),
+                _ => None,
+            })
+    }
+
+    /**
+     * Creates class defined by the loader, superclass and superinterfaces are loaded
+     * by the same loader first.
+     */
+    fn define_class(
+        &mut self,
+        class_file: ClassFile,
+        loader: LoaderId,
+        thread_id: i64,
+    ) -> Result<Arc<JvmClass>, Error> {
+        let class_name = class_file.get_class_name().to_string();
+
+        self.start_definition(&class_name, loader, thread_id)?;
+
+        let result = self.create_class(class_file, loader, thread_id);
+        self.finish_definition(&class_name, loader, result.as_ref().ok());
+
+        result
+    }
+
+    /**
+     * Marks class as being loaded by its defining loader in the given thread, loader can define
+     * every class once only.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3.5
+     */
+    pub fn start_definition(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+        thread_id: i64,
+    ) -> Result<(), Error> {
+        match self.get_class_state(class_name, loader) {
+            Some(ClassState::Loading(loading_thread_id)) => Err(class_loading_error(
+                class_name,
+                *loading_thread_id,
+                thread_id,
+            )),
+            Some(ClassState::Loaded(_)) => Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!(
+                    "java.lang.LinkageError: loader {} attempted duplicate class definition for {}.",
+                    loader.get_id(),
+                    class_name.replace('/', ".")
+                ),
+            )),
+            None => {
+                self.classes.insert(
+                    (class_name.to_string(), loader),
+                    ClassState::Loading(thread_id),
+                );
+                Ok(())
+            }
         }
     }
 
-    pub fn load_class(&self, class_name: &str) -> Result<ClassFile, Error> {
-        let class_path_folder = &self.class_path_folder;
+    /**
+     * Registers class created after 'start_definition', 'None' means that definition failed.
+     */
+    pub fn finish_definition(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+        class: Option<&Arc<JvmClass>>,
+    ) {
+        let key = (class_name.to_string(), loader);
+
+        match class {
+            Some(class) => self
+                .classes
+                .insert(key, ClassState::Loaded(Arc::clone(class))),
+            None => self.classes.remove(&key),
+        };
+    }
+
+    /**
+     * Records that the class was loaded by delegation from 'loader', so the loader
+     * gets the same class next time.
+     */
+    pub fn record_initiating_loader(&mut self, loader: LoaderId, class: &Arc<JvmClass>) {
+        self.classes
+            .entry((class.get_name().to_string(), loader))
+            .or_insert_with(|| ClassState::Loaded(Arc::clone(class)));
+    }
+
+    /**
+     * Assigns number to the new user-defined loader.
+     */
+    pub fn register_user_loader(&mut self) -> LoaderId {
+        self.last_user_loader_id += 1;
+        LoaderId::User(self.last_user_loader_id)
+    }
 
-        let full_class_path = format!(
-            "{}/{}.class",
-            class_path_folder,
-            Self::class_name_to_path(class_name)
-        );
+    fn create_class(
+        &mut self,
+        class_file: ClassFile,
+        loader: LoaderId,
+        thread_id: i64,
+    ) -> Result<Arc<JvmClass>, Error> {
+        let super_class = match class_file.get_super_class_name() {
+            Some(super_class_name) => Some(self.load_class(super_class_name, loader, thread_id)?),
+            None => None,
+        };
 
-        println!(
-            "Loading class '{}' into JVM with path: '{}'",
-            class_name, full_class_path
-        );
+        let mut interfaces = Vec::new();
+        for interface_name in class_file.get_interface_names()? {
+            interfaces.push(self.load_class(&interface_name, loader, thread_id)?);
+        }
 
-        let mut class_file_buf =
-            BufReader::new(File::open(full_class_path).expect("Can't open file"));
-        let mut buf = Vec::new();
+        JvmClass::new(class_file, loader, super_class, interfaces)
+    }
 
-        class_file_buf.read_to_end(&mut buf)?;
+    /**
+     * Reads and parses class file from the class path of the loader, 'None' is returned
+     * if there is no such class file. Class path entries are searched in order.
+     */
+    fn read_class_file(
+        &self,
+        class_name: &str,
+        loader: LoaderId,
+    ) -> Result<Option<ClassFile>, Error> {
+        let class_file_name = format!("{}.class", class_name);
 
-        let mut raw_file_data = RawByteBuffer {
-            cursor: 0,
-            data: buf,
+        let class_path_entries = match loader {
+            LoaderId::Bootstrap => std::slice::from_ref(&self.bootstrap_class_path),
+            LoaderId::Application => self.class_path.as_slice(),
+            LoaderId::User(_) => &[],
         };
 
-        let loaded_class = ClassFile::new(&mut raw_file_data)?;
+        for entry in class_path_entries {
+            let Some((data, location)) = entry.read(&class_file_name)? else {
+                continue;
+            };
+
+            println!(
+                "Loading class '{}' into JVM with path: '{}'",
+                class_name, location
+            );
+
+            let mut raw_file_data = RawByteBuffer { cursor: 0, data };
+
+            let loaded_class = ClassFile::new(&mut raw_file_data)?;
+
+            if loaded_class.get_class_name() != class_name {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "java.lang.NoClassDefFoundError: {} (wrong name: {})",
+                        class_name,
+                        loaded_class.get_class_name()
+                    ),
+                ));
+            }
+
+            return Ok(Some(loaded_class));
+        }
 
-        Ok(loaded_class)
+        Ok(None)
     }
 
     fn class_name_to_path(class_name: &str) -> String {
         class_name.replace('.', "/")
     }
 }
+
+/**
+ * Searches 'java/rt' folder in the folder of the executable and its parent folders, so the same
+ * lookup works for the installed binary and for the one inside cargo 'target' folder.
+ */
+fn find_bootstrap_class_path() -> Result<PathBuf, Error> {
+    let executable = env::current_exe()?;
+
+    executable
+        .ancestors()
+        .skip(1)
+        .map(|folder| folder.join(BOOTSTRAP_CLASS_PATH_FOLDER))
+        .find(|folder| folder.join(BOOTSTRAP_MARKER_CLASS).is_file())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!(
+                    "Can't find bootstrap classes near '{}', use -Xbootclasspath option",
+                    executable.display()
+                ),
+            )
+        })
+}
+
+fn class_not_found_error(class_name: &str) -> Error {
+    Error::new(
+        ErrorKind::NotFound,
+        format!("java.lang.NoClassDefFoundError: {}", class_name),
+    )
+}
+
+/**
+ * Error for the class found in 'Loading' state. Class is its own superclass or superinterface
+ * if the current thread loads it already, otherwise the current thread has to wait.
+ */
+fn class_loading_error(class_name: &str, loading_thread_id: i64, thread_id: i64) -> Error {
+    if loading_thread_id == thread_id {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("java.lang.ClassCircularityError: {}", class_name),
+        )
+    } else {
+        Error::new(
+            ErrorKind::WouldBlock,
+            format!("Class '{}' is being loaded by another thread", class_name),
+        )
+    }
+}
+
+impl ClassPathEntry {
+    /**
+     * Files with '.jar' or '.zip' extension are opened as archives, any other path is treated
+     * as directory. Like in 'java', entries which don't exist are allowed and never contain classes.
+     */
+    fn open(path: PathBuf) -> Result<Self, Error> {
+        let is_archive = path.is_file()
+            && path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    extension.eq_ignore_ascii_case("jar") || extension.eq_ignore_ascii_case("zip")
+                });
+
+        if is_archive {
+            Ok(ClassPathEntry::Archive(ZipArchive::open(&path)?))
+        } else {
+            Ok(ClassPathEntry::Directory(path))
+        }
+    }
+
+    /**
+     * Reads file with the given relative path, like 'com/max/Hello.class', together with
+     * its location for diagnostics. 'None' is returned if the entry doesn't contain the file.
+     */
+    fn read(&self, file_name: &str) -> Result<Option<(Vec<u8>, String)>, Error> {
+        match self {
+            ClassPathEntry::Directory(folder) => {
+                let full_path = folder.join(file_name);
+
+                if !full_path.is_file() {
+                    return Ok(None);
+                }
+
+                let data = fs::read(&full_path)?;
+                Ok(Some((data, full_path.display().to_string())))
+            }
+            ClassPathEntry::Archive(archive) => Ok(archive.read(file_name)?.map(|data| {
+                let location = format!("{}!/{}", archive.get_path().display(), file_name);
+                (data, location)
+            })),
+        }
+    }
+}
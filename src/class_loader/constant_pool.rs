@@ -21,16 +21,150 @@ impl ConstantPool {
         // constant_pool starts with 1 so we just need to push fake value as 0-based
         values.push(ConstantType::Reserved);
 
-        for _ in 1..constant_pool_count {
+        while values.len() < constant_pool_count {
             let single_value = ConstantType::from(data)?;
+
+            // long and double constants take two entries, the second one is not usable
+            // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4.5
+            let is_wide = matches!(
+                single_value,
+                ConstantType::Long { .. } | ConstantType::Double { .. }
+            );
+
             values.push(single_value);
+
+            if is_wide {
+                values.push(ConstantType::Reserved);
+            }
+        }
+
+        let constant_pool = ConstantPool { values };
+        constant_pool.check_references()?;
+
+        Ok(constant_pool)
+    }
+
+    /**
+     * Checks that every index stored in the constant pool points to an entry of the expected
+     * kind, so later lookups can't go out of range or loop forever.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4
+     */
+    fn check_references(&self) -> Result<(), Error> {
+        let is_utf8 = |value: &ConstantType| matches!(value, ConstantType::Utf8 { .. });
+        let is_class = |value: &ConstantType| matches!(value, ConstantType::Class { .. });
+        let is_name_and_type =
+            |value: &ConstantType| matches!(value, ConstantType::NameAndType { .. });
+        let is_member_ref = |value: &ConstantType| {
+            matches!(
+                value,
+                ConstantType::Fieldref { .. }
+                    | ConstantType::Methodref { .. }
+                    | ConstantType::InterfaceMethodref { .. }
+            )
+        };
+
+        for (index, value) in self.values.iter().enumerate() {
+            match value {
+                ConstantType::Class {
+                    name_index: utf8_index,
+                }
+                | ConstantType::String { idx: utf8_index }
+                | ConstantType::MethodType {
+                    descriptor_index: utf8_index,
+                }
+                | ConstantType::Module {
+                    name_index: utf8_index,
+                }
+                | ConstantType::Package {
+                    name_index: utf8_index,
+                } => {
+                    self.check_reference(*utf8_index, is_utf8, "Utf8")?;
+                }
+                ConstantType::Fieldref {
+                    class_index,
+                    name_and_type_index,
+                }
+                | ConstantType::Methodref {
+                    class_index,
+                    name_and_type_index,
+                }
+                | ConstantType::InterfaceMethodref {
+                    class_index,
+                    name_and_type_index,
+                } => {
+                    self.check_reference(*class_index, is_class, "Class")?;
+                    self.check_reference(*name_and_type_index, is_name_and_type, "NameAndType")?;
+                }
+                ConstantType::NameAndType {
+                    name_index,
+                    descriptor_index,
+                } => {
+                    self.check_reference(*name_index, is_utf8, "Utf8")?;
+                    self.check_reference(*descriptor_index, is_utf8, "Utf8")?;
+                }
+                ConstantType::MethodHandle {
+                    reference_index, ..
+                } => {
+                    self.check_reference(*reference_index, is_member_ref, "member reference")?;
+                }
+                ConstantType::Dynamic {
+                    name_and_type_index,
+                    ..
+                }
+                | ConstantType::InvokeDynamic {
+                    name_and_type_index,
+                    ..
+                } => {
+                    self.check_reference(*name_and_type_index, is_name_and_type, "NameAndType")?;
+                }
+                ConstantType::Undefined => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Unknown constant tag in constant_pool at index {}", index),
+                    ));
+                }
+                _ => {}
+            }
         }
 
-        Ok(ConstantPool { values })
+        Ok(())
+    }
+
+    fn check_reference(
+        &self,
+        index: u16,
+        is_expected: impl Fn(&ConstantType) -> bool,
+        expected: &str,
+    ) -> Result<(), Error> {
+        if is_expected(self.get_constant(index as usize)?) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Expected {} in constant_pool at index {}", expected, index),
+            ))
+        }
+    }
+
+    /**
+     * Number of constant pool slots including unusable index 0 and slots following
+     * long and double constants.
+     */
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn get_constant(&self, index: usize) -> Result<&ConstantType, Error> {
+        self.values.get(index).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid constant_pool index {}", index),
+            )
+        })
     }
 
     pub fn resolve_constant_pool_utf(&self, index: usize) -> Result<String, Error> {
-        match &self.values[index] {
+        match self.get_constant(index)? {
             ConstantType::Utf8 { value } => Ok(value.to_string()),
             ConstantType::Class { name_index } => {
                 self.resolve_constant_pool_utf(*name_index as usize)
@@ -69,6 +203,67 @@ impl ConstantPool {
             )),
         }
     }
+
+    /**
+     * Resolves Fieldref, Methodref or InterfaceMethodref constant into a member reference.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4.2
+     */
+    pub fn resolve_member_ref(&self, index: usize) -> Result<MemberRef, Error> {
+        match self.get_constant(index)? {
+            ConstantType::Fieldref {
+                class_index,
+                name_and_type_index,
+            }
+            | ConstantType::Methodref {
+                class_index,
+                name_and_type_index,
+            }
+            | ConstantType::InterfaceMethodref {
+                class_index,
+                name_and_type_index,
+            } => {
+                let class_name = self.resolve_constant_pool_utf(*class_index as usize)?;
+
+                if let ConstantType::NameAndType {
+                    name_index,
+                    descriptor_index,
+                } = self.get_constant(*name_and_type_index as usize)?
+                {
+                    Ok(MemberRef {
+                        class_name,
+                        name: self.resolve_constant_pool_utf(*name_index as usize)?,
+                        descriptor: self.resolve_constant_pool_utf(*descriptor_index as usize)?,
+                    })
+                } else {
+                    Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "Expected NameAndType in constant_pool at index {}",
+                            name_and_type_index
+                        ),
+                    ))
+                }
+            }
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Expected member reference in constant_pool at index {}",
+                    index
+                ),
+            )),
+        }
+    }
+}
+
+/**
+ * Symbolic reference to a field or method of some class.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MemberRef {
+    // class name in internal form, like 'com/max/Hello'
+    pub class_name: String,
+    pub name: String,
+    pub descriptor: String,
 }
 
 /**
@@ -191,9 +386,60 @@ pub enum ConstantType {
     Utf8 {
         value: String,
     },
-    MethodHandle,
-    MethodType,
-    InvokeDynamic,
+    /*
+    CONSTANT_MethodHandle_info {
+        u1 tag;
+        u1 reference_kind;
+        u2 reference_index;
+    }
+    */
+    MethodHandle {
+        reference_kind: u8,
+        reference_index: u16,
+    },
+    /*
+    CONSTANT_MethodType_info {
+        u1 tag;
+        u2 descriptor_index;
+    }
+    */
+    MethodType {
+        descriptor_index: u16,
+    },
+    /*
+    CONSTANT_Dynamic_info {
+        u1 tag;
+        u2 bootstrap_method_attr_index;
+        u2 name_and_type_index;
+    }
+    */
+    Dynamic {
+        bootstrap_method_attr_index: u16,
+        name_and_type_index: u16,
+    },
+    /*
+    CONSTANT_InvokeDynamic_info {
+        u1 tag;
+        u2 bootstrap_method_attr_index;
+        u2 name_and_type_index;
+    }
+    */
+    InvokeDynamic {
+        bootstrap_method_attr_index: u16,
+        name_and_type_index: u16,
+    },
+    /*
+    CONSTANT_Module_info and CONSTANT_Package_info {
+        u1 tag;
+        u2 name_index;
+    }
+    */
+    Module {
+        name_index: u16,
+    },
+    Package {
+        name_index: u16,
+    },
     // Reserved type will be used to replace 0-based value in constant pool
     // which doesn't exists in real class file
     Reserved,
@@ -245,9 +491,27 @@ impl ConstantType {
                     value: data.read_string(str_length)?,
                 })
             }
-            15 => Ok(Self::MethodHandle),
-            16 => Ok(Self::MethodType),
-            18 => Ok(Self::InvokeDynamic),
+            15 => Ok(Self::MethodHandle {
+                reference_kind: data.read_1_byte()?,
+                reference_index: data.read_2_bytes()?,
+            }),
+            16 => Ok(Self::MethodType {
+                descriptor_index: data.read_2_bytes()?,
+            }),
+            17 => Ok(Self::Dynamic {
+                bootstrap_method_attr_index: data.read_2_bytes()?,
+                name_and_type_index: data.read_2_bytes()?,
+            }),
+            18 => Ok(Self::InvokeDynamic {
+                bootstrap_method_attr_index: data.read_2_bytes()?,
+                name_and_type_index: data.read_2_bytes()?,
+            }),
+            19 => Ok(Self::Module {
+                name_index: data.read_2_bytes()?,
+            }),
+            20 => Ok(Self::Package {
+                name_index: data.read_2_bytes()?,
+            }),
             _ => Ok(Self::Undefined),
         }
     }
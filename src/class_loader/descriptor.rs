@@ -0,0 +1,158 @@
+use std::io::{Error, ErrorKind};
+
+/**
+ * Field descriptor https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.3.2
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Byte,
+    Char,
+    Double,
+    Float,
+    Int,
+    Long,
+    Short,
+    Boolean,
+    // class name in internal form, like 'java/lang/Object'
+    Object(String),
+    Array(Box<FieldType>),
+}
+
+impl FieldType {
+    pub fn parse(descriptor: &str) -> Result<FieldType, Error> {
+        let mut offset = 0;
+
+        let field_type = Self::read(descriptor, &mut offset)?;
+
+        if offset != descriptor.len() {
+            return Err(Self::invalid_descriptor(descriptor));
+        }
+
+        Ok(field_type)
+    }
+
+    /**
+     * long and double values occupy two local variable slots
+     */
+    pub fn slots_count(&self) -> u16 {
+        match self {
+            FieldType::Long | FieldType::Double => 2,
+            _ => 1,
+        }
+    }
+
+    /**
+     * Field descriptor of the type, the reverse of 'parse'.
+     */
+    pub fn to_descriptor(&self) -> String {
+        match self {
+            FieldType::Byte => "B".to_string(),
+            FieldType::Char => "C".to_string(),
+            FieldType::Double => "D".to_string(),
+            FieldType::Float => "F".to_string(),
+            FieldType::Int => "I".to_string(),
+            FieldType::Long => "J".to_string(),
+            FieldType::Short => "S".to_string(),
+            FieldType::Boolean => "Z".to_string(),
+            FieldType::Object(class_name) => format!("L{};", class_name),
+            FieldType::Array(component_type) => format!("[{}", component_type.to_descriptor()),
+        }
+    }
+
+    /**
+     * Reads single field type starting at 'offset' and moves 'offset' right after it.
+     */
+    fn read(descriptor: &str, offset: &mut usize) -> Result<FieldType, Error> {
+        let bytes = descriptor.as_bytes();
+
+        let tag = *bytes
+            .get(*offset)
+            .ok_or_else(|| Self::invalid_descriptor(descriptor))?;
+        *offset += 1;
+
+        match tag {
+            b'B' => Ok(FieldType::Byte),
+            b'C' => Ok(FieldType::Char),
+            b'D' => Ok(FieldType::Double),
+            b'F' => Ok(FieldType::Float),
+            b'I' => Ok(FieldType::Int),
+            b'J' => Ok(FieldType::Long),
+            b'S' => Ok(FieldType::Short),
+            b'Z' => Ok(FieldType::Boolean),
+            b'L' => {
+                let start = *offset;
+
+                let length = descriptor[start..]
+                    .find(';')
+                    .ok_or_else(|| Self::invalid_descriptor(descriptor))?;
+                *offset += length + 1;
+
+                Ok(FieldType::Object(
+                    descriptor[start..start + length].to_string(),
+                ))
+            }
+            b'[' => Ok(FieldType::Array(Box::new(Self::read(descriptor, offset)?))),
+            _ => Err(Self::invalid_descriptor(descriptor)),
+        }
+    }
+
+    fn invalid_descriptor(descriptor: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Can't parse descriptor '{}'", descriptor),
+        )
+    }
+}
+
+/**
+ * Method descriptor https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.3.3
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodDescriptor {
+    pub parameters: Vec<FieldType>,
+    // 'None' for the 'void' return type
+    pub return_type: Option<FieldType>,
+}
+
+impl MethodDescriptor {
+    pub fn parse(descriptor: &str) -> Result<MethodDescriptor, Error> {
+        if !descriptor.starts_with('(') {
+            return Err(FieldType::invalid_descriptor(descriptor));
+        }
+
+        let mut offset = 1;
+        let mut parameters = Vec::new();
+
+        while descriptor.as_bytes().get(offset) != Some(&b')') {
+            parameters.push(FieldType::read(descriptor, &mut offset)?);
+        }
+        offset += 1;
+
+        let return_type = if &descriptor[offset..] == "V" {
+            None
+        } else {
+            let single_type = FieldType::read(descriptor, &mut offset)?;
+
+            if offset != descriptor.len() {
+                return Err(FieldType::invalid_descriptor(descriptor));
+            }
+
+            Some(single_type)
+        };
+
+        Ok(MethodDescriptor {
+            parameters,
+            return_type,
+        })
+    }
+
+    /**
+     * Number of local variable slots occupied by method parameters, without 'this'.
+     */
+    pub fn parameters_slots_count(&self) -> u16 {
+        self.parameters
+            .iter()
+            .map(|param| param.slots_count())
+            .sum()
+    }
+}
@@ -1,5 +1,6 @@
 use std::io::Error;
 
+use crate::class_loader::attribute_info::AttributeInfo;
 use crate::class_loader::constant_pool::ConstantPool;
 use crate::class_loader::raw_data::RawByteBuffer;
 
@@ -17,9 +18,9 @@ field_info {
 pub struct FieldInfo {
     access_flags: Vec<FieldAcceFlag>,
     name: String,
-    descriptor_name: String,
     // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.3.2
-    attributes_count: u16,
+    descriptor_name: String,
+    attributes: Vec<AttributeInfo>,
 }
 
 impl FieldInfo {
@@ -38,21 +39,52 @@ impl FieldInfo {
 
         let attributes_count = data.read_2_bytes()?;
 
+        let mut attributes = Vec::with_capacity(attributes_count as usize);
+
         // Read all attribute_info[attributes_count] here
         for _ in 0..attributes_count {
-            todo!("read single attribute_info here")
+            attributes.push(AttributeInfo::from(data, constant_pool)?);
         }
 
         Ok(FieldInfo {
             access_flags: field_access_flags,
             name: field_name,
             descriptor_name,
-            attributes_count,
+            attributes,
+        })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_descriptor(&self) -> &str {
+        &self.descriptor_name
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.access_flags.contains(&FieldAcceFlag::Static)
+    }
+
+    /**
+     * Constant pool index of the value from ConstantValue attribute, if any.
+     */
+    pub fn get_constant_value_index(&self) -> Option<u16> {
+        self.attributes.iter().find_map(|single_attribute| {
+            if let AttributeInfo::ConstantValue {
+                name: _,
+                constant_value_index,
+            } = single_attribute
+            {
+                Some(*constant_value_index)
+            } else {
+                None
+            }
         })
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum FieldAcceFlag {
     Public = 0x0001,
     Private = 0x0002,
@@ -0,0 +1,414 @@
+use std::io::{Error, ErrorKind};
+
+/**
+ * Decompresses data stored with DEFLATE algorithm, used by ZIP archive entries with
+ * compression method 8.
+ * https://www.rfc-editor.org/rfc/rfc1951
+ */
+pub fn inflate(data: &[u8], expected_size: usize) -> Result<Vec<u8>, Error> {
+    let mut input = BitReader::new(data);
+
+    // expected size is taken from the archive as is, so the output grows beyond the limit on demand
+    let mut output = Vec::with_capacity(expected_size.min(MAX_INITIAL_CAPACITY));
+
+    loop {
+        let last_block = input.read_bits(1)? == 1;
+
+        match input.read_bits(2)? {
+            0 => copy_stored_block(&mut input, &mut output)?,
+            1 => {
+                let (literals, distances) = fixed_huffman_tables();
+                inflate_block(&mut input, &mut output, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = read_dynamic_huffman_tables(&mut input)?;
+                inflate_block(&mut input, &mut output, &literals, &distances)?;
+            }
+            _ => return Err(invalid_data("invalid block type")),
+        }
+
+        if last_block {
+            return Ok(output);
+        }
+    }
+}
+
+// base lengths and number of extra bits for length symbols 257..285
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+// base distances and number of extra bits for distance symbols 0..29
+const DISTANCE_BASES: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA_BITS: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+// order in which code lengths of the code length alphabet are stored
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+const MAX_CODE_LENGTH: usize = 15;
+
+const MAX_INITIAL_CAPACITY: usize = 1024 * 1024;
+
+const END_OF_BLOCK: u16 = 256;
+
+/**
+ * Reads bits starting from the least significant bit of every byte.
+ */
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            position: 0,
+            bit_buffer: 0,
+            bit_count: 0,
+        }
+    }
+
+    fn read_bits(&mut self, count: u32) -> Result<u32, Error> {
+        while self.bit_count < count {
+            let Some(&byte) = self.data.get(self.position) else {
+                return Err(invalid_data("unexpected end of data"));
+            };
+
+            self.bit_buffer |= (byte as u32) << self.bit_count;
+            self.bit_count += 8;
+            self.position += 1;
+        }
+
+        let value = self.bit_buffer & ((1u64 << count) - 1) as u32;
+
+        self.bit_buffer >>= count;
+        self.bit_count -= count;
+
+        Ok(value)
+    }
+
+    /**
+     * Drops the rest of the current byte.
+     */
+    fn align_to_byte(&mut self) {
+        self.bit_buffer = 0;
+        self.bit_count = 0;
+    }
+
+    fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + count)
+            .ok_or_else(|| invalid_data("unexpected end of data"))?;
+
+        self.position += count;
+        Ok(bytes)
+    }
+}
+
+/**
+ * Canonical Huffman code, symbols are sorted by code length and then by symbol value.
+ */
+struct HuffmanTable {
+    // number of codes of every length
+    counts: [u16; MAX_CODE_LENGTH + 1],
+    symbols: Vec<u16>,
+}
+
+impl HuffmanTable {
+    fn new(code_lengths: &[u8]) -> Self {
+        let mut counts = [0u16; MAX_CODE_LENGTH + 1];
+
+        for &length in code_lengths {
+            counts[length as usize] += 1;
+        }
+
+        // symbols without code have zero length
+        counts[0] = 0;
+
+        let mut symbols = Vec::with_capacity(code_lengths.len());
+
+        for length in 1..=MAX_CODE_LENGTH {
+            for (symbol, &symbol_length) in code_lengths.iter().enumerate() {
+                if symbol_length as usize == length {
+                    symbols.push(symbol as u16);
+                }
+            }
+        }
+
+        Self { counts, symbols }
+    }
+
+    /**
+     * Reads code bit by bit, codes of every length are consecutive numbers,
+     * so the code is found as soon as it's inside the range of its length.
+     */
+    fn decode(&self, input: &mut BitReader) -> Result<u16, Error> {
+        let mut code = 0;
+        let mut first = 0;
+        let mut index = 0;
+
+        for length in 1..=MAX_CODE_LENGTH {
+            code |= input.read_bits(1)? as i32;
+
+            let count = self.counts[length] as i32;
+
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+
+        Err(invalid_data("invalid Huffman code"))
+    }
+}
+
+fn copy_stored_block(input: &mut BitReader, output: &mut Vec<u8>) -> Result<(), Error> {
+    input.align_to_byte();
+
+    let header = input.read_bytes(4)?;
+    let length = u16::from_le_bytes([header[0], header[1]]);
+    let length_complement = u16::from_le_bytes([header[2], header[3]]);
+
+    if length != !length_complement {
+        return Err(invalid_data(
+            "stored block length doesn't match its complement",
+        ));
+    }
+
+    output.extend_from_slice(input.read_bytes(length as usize)?);
+    Ok(())
+}
+
+fn fixed_huffman_tables() -> (HuffmanTable, HuffmanTable) {
+    let mut literal_lengths = [0u8; 288];
+
+    literal_lengths[..144].fill(8);
+    literal_lengths[144..256].fill(9);
+    literal_lengths[256..280].fill(7);
+    literal_lengths[280..].fill(8);
+
+    (
+        HuffmanTable::new(&literal_lengths),
+        HuffmanTable::new(&[5; 30]),
+    )
+}
+
+fn read_dynamic_huffman_tables(
+    input: &mut BitReader,
+) -> Result<(HuffmanTable, HuffmanTable), Error> {
+    let literals_count = input.read_bits(5)? as usize + 257;
+    let distances_count = input.read_bits(5)? as usize + 1;
+    let code_lengths_count = input.read_bits(4)? as usize + 4;
+
+    let mut code_length_lengths = [0u8; 19];
+
+    for &symbol in &CODE_LENGTH_ORDER[..code_lengths_count] {
+        code_length_lengths[symbol] = input.read_bits(3)? as u8;
+    }
+
+    let code_lengths_table = HuffmanTable::new(&code_length_lengths);
+
+    // literal/length and distance code lengths are stored as a single sequence
+    let mut lengths = Vec::with_capacity(literals_count + distances_count);
+
+    while lengths.len() < literals_count + distances_count {
+        let symbol = code_lengths_table.decode(input)?;
+
+        let (length, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths
+                    .last()
+                    .ok_or_else(|| invalid_data("no code length to repeat"))?;
+                (previous, 3 + input.read_bits(2)?)
+            }
+            17 => (0, 3 + input.read_bits(3)?),
+            18 => (0, 11 + input.read_bits(7)?),
+            _ => return Err(invalid_data("invalid code length symbol")),
+        };
+
+        for _ in 0..repeat {
+            lengths.push(length);
+        }
+    }
+
+    if lengths.len() > literals_count + distances_count {
+        return Err(invalid_data("too many code lengths"));
+    }
+
+    let (literal_lengths, distance_lengths) = lengths.split_at(literals_count);
+
+    Ok((
+        HuffmanTable::new(literal_lengths),
+        HuffmanTable::new(distance_lengths),
+    ))
+}
+
+fn inflate_block(
+    input: &mut BitReader,
+    output: &mut Vec<u8>,
+    literals: &HuffmanTable,
+    distances: &HuffmanTable,
+) -> Result<(), Error> {
+    loop {
+        let symbol = literals.decode(input)?;
+
+        if symbol < END_OF_BLOCK {
+            output.push(symbol as u8);
+            continue;
+        }
+
+        if symbol == END_OF_BLOCK {
+            return Ok(());
+        }
+
+        // copy of the previously decompressed data
+        let length_symbol = (symbol - 257) as usize;
+
+        if length_symbol >= LENGTH_BASES.len() {
+            return Err(invalid_data("invalid length symbol"));
+        }
+
+        let length = LENGTH_BASES[length_symbol] as usize
+            + input.read_bits(LENGTH_EXTRA_BITS[length_symbol] as u32)? as usize;
+
+        let distance_symbol = distances.decode(input)? as usize;
+
+        if distance_symbol >= DISTANCE_BASES.len() {
+            return Err(invalid_data("invalid distance symbol"));
+        }
+
+        let distance = DISTANCE_BASES[distance_symbol] as usize
+            + input.read_bits(DISTANCE_EXTRA_BITS[distance_symbol] as u32)? as usize;
+
+        if distance > output.len() {
+            return Err(invalid_data("distance is too far back"));
+        }
+
+        // source and destination may overlap, so bytes are copied one by one
+        let start = output.len() - distance;
+
+        for offset in 0..length {
+            output.push(output[start + offset]);
+        }
+    }
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid deflate data: {}", message),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 'hello' in a single stored block
+    const STORED: [u8; 10] = [1, 5, 0, 250, 255, 104, 101, 108, 108, 111];
+
+    // 'abc' compressed with fixed Huffman codes
+    const FIXED: [u8; 5] = [75, 76, 74, 6, 0];
+
+    // 20 'a' characters: literal followed by a copy with distance 1
+    const OVERLAPPING_COPY: [u8; 5] = [75, 76, 196, 4, 0];
+
+    // output of 'squares_text' compressed with dynamic Huffman codes
+    const DYNAMIC: [u8; 81] = [
+        85, 142, 203, 13, 128, 48, 12, 67, 87, 241, 8, 73, 250, 161, 85, 166, 65, 130, 3, 71, 168,
+        216, 159, 112, 115, 142, 239, 201, 178, 45, 88, 247, 187, 63, 231, 129, 107, 65, 28, 202,
+        172, 14, 99, 174, 142, 194, 60, 29, 53, 229, 187, 163, 177, 176, 230, 232, 44, 74, 36, 182,
+        84, 25, 29, 131, 69, 143, 145, 201, 98, 196, 11, 149, 52, 35, 255, 209, 252, 212, 34, 245,
+        1,
+    ];
+
+    fn squares_text() -> Vec<u8> {
+        (0..12)
+            .map(|i| format!("{} squared is {}; ", i, i * i))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    #[test]
+    fn inflates_stored_block() {
+        assert_eq!(inflate(&STORED, 5).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn inflates_fixed_huffman_block() {
+        assert_eq!(inflate(&FIXED, 3).unwrap(), b"abc");
+    }
+
+    #[test]
+    fn inflates_dynamic_huffman_block() {
+        let expected = squares_text();
+        assert_eq!(inflate(&DYNAMIC, expected.len()).unwrap(), expected);
+    }
+
+    #[test]
+    fn copies_overlapping_back_reference() {
+        assert_eq!(inflate(&OVERLAPPING_COPY, 20).unwrap(), [b'a'; 20]);
+    }
+
+    #[test]
+    fn expected_size_is_only_a_hint() {
+        assert_eq!(inflate(&FIXED, 0).unwrap(), b"abc");
+        assert_eq!(inflate(&FIXED, usize::MAX).unwrap(), b"abc");
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        for data in [&STORED[..], &FIXED[..], &DYNAMIC[..]] {
+            for length in 0..data.len() - 1 {
+                assert!(inflate(&data[..length], 0).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_block_type() {
+        assert!(inflate(&[0b111], 0).is_err());
+    }
+
+    #[test]
+    fn rejects_stored_block_with_wrong_length_complement() {
+        assert!(inflate(&[1, 5, 0, 0, 0, 104, 101, 108, 108, 111], 5).is_err());
+    }
+
+    #[test]
+    fn rejects_distance_before_start_of_output() {
+        // fixed block starting with a copy of length 3 and distance 1, nothing to copy yet
+        assert!(inflate(&[3, 2, 0], 0).is_err());
+    }
+
+    #[test]
+    fn never_panics_on_corrupt_data() {
+        for position in 0..DYNAMIC.len() {
+            for bit in 0..8 {
+                let mut data = DYNAMIC;
+                data[position] ^= 1 << bit;
+                let _ = inflate(&data, 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::class_loader::zip_archive::ZipArchive;
+
+const MANIFEST_ENTRY_NAME: &str = "META-INF/MANIFEST.MF";
+
+/**
+ * Main attributes of the JAR manifest, attributes of the per-entry sections are ignored.
+ * https://docs.oracle.com/en/java/javase/17/docs/specs/jar/jar.html#jar-manifest
+ */
+pub struct JarManifest {
+    // path of the JAR file, relative 'Class-Path' entries are resolved against its directory
+    jar_path: PathBuf,
+
+    // attribute names are case-insensitive, so they are stored in lower case
+    main_attributes: HashMap<String, String>,
+}
+
+impl JarManifest {
+    /**
+     * Reads manifest of the JAR file, archive without manifest has no attributes.
+     */
+    pub fn read(jar_path: &Path) -> Result<Self, Error> {
+        let archive = ZipArchive::open(jar_path)?;
+
+        let text = match archive.read(MANIFEST_ENTRY_NAME)? {
+            Some(data) => String::from_utf8(data).map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Manifest of '{}' is not UTF-8", jar_path.display()),
+                )
+            })?,
+            None => String::new(),
+        };
+
+        Ok(Self {
+            jar_path: jar_path.to_path_buf(),
+            main_attributes: parse_main_attributes(&text),
+        })
+    }
+
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.main_attributes
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /**
+     * Binary name of the class with 'main' method, like 'com.max.Hello'.
+     */
+    pub fn get_main_class(&self) -> Option<&str> {
+        self.get_attribute("Main-Class")
+    }
+
+    /**
+     * Entries of 'Class-Path' attribute separated by spaces, they are relative URLs of
+     * directories or JAR files resolved against the directory of the JAR file.
+     */
+    pub fn get_class_path(&self) -> Vec<PathBuf> {
+        let jar_folder = self.jar_path.parent().unwrap_or(Path::new(""));
+
+        self.get_attribute("Class-Path")
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|url| jar_folder.join(decode_url_path(url.strip_prefix("file:").unwrap_or(url))))
+            .collect()
+    }
+}
+
+/**
+ * Replaces escaped octets like '%20' of the URL path by the bytes they stand for,
+ * malformed escapes are kept as is.
+ */
+fn decode_url_path(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escaped = bytes
+            .get(index + 1..index + 3)
+            .filter(|digits| bytes[index] == b'%' && digits.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/**
+ * Main section is terminated by the first empty line. Every line longer than 72 bytes
+ * is split, continuation lines start with a single space.
+ */
+fn parse_main_attributes(text: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in text.lines() {
+        // 'lines' splits on '\n' and '\r\n' only, but manifest may use bare '\r' as well
+        for line in line.split('\r') {
+            if let Some(continuation) = line.strip_prefix(' ') {
+                if let Some((_, value)) = &mut current {
+                    value.push_str(continuation);
+                }
+                continue;
+            }
+
+            if let Some((name, value)) = current.take() {
+                attributes.insert(name, value);
+            }
+
+            if line.is_empty() {
+                return attributes;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                let value = value.strip_prefix(' ').unwrap_or(value);
+                current = Some((name.trim().to_ascii_lowercase(), value.to_string()));
+            }
+        }
+    }
+
+    if let Some((name, value)) = current {
+        attributes.insert(name, value);
+    }
+
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn manifest(jar_path: &str, text: &str) -> JarManifest {
+        JarManifest {
+            jar_path: PathBuf::from(jar_path),
+            main_attributes: parse_main_attributes(text),
+        }
+    }
+
+    #[test]
+    fn reads_main_attributes() {
+        let manifest = manifest(
+            "app.jar",
+            "Manifest-Version: 1.0\nMain-Class: com.max.Hello\n\nName: a.class\nX: y\n",
+        );
+
+        assert_eq!(manifest.get_attribute("Manifest-Version"), Some("1.0"));
+        assert_eq!(manifest.get_main_class(), Some("com.max.Hello"));
+        assert_eq!(manifest.get_attribute("X"), None);
+    }
+
+    #[test]
+    fn joins_continuation_lines() {
+        let manifest = manifest(
+            "app.jar",
+            "Main-Class: com.max.very.long.packa\n ge.Hello\nClass-Path: a.jar\n  b.jar\n",
+        );
+
+        assert_eq!(
+            manifest.get_main_class(),
+            Some("com.max.very.long.package.Hello")
+        );
+        assert_eq!(manifest.get_attribute("Class-Path"), Some("a.jar b.jar"));
+    }
+
+    #[test]
+    fn accepts_crlf_line_endings() {
+        let manifest = manifest(
+            "app.jar",
+            "Main-Class: com.max.He\r\n llo\r\n\r\nClass-Path: a.jar\r\n",
+        );
+
+        assert_eq!(manifest.get_main_class(), Some("com.max.Hello"));
+        assert_eq!(manifest.get_attribute("Class-Path"), None);
+    }
+
+    #[test]
+    fn accepts_bare_cr_line_endings() {
+        let manifest = manifest(
+            "app.jar",
+            "Main-Class: com.max.He\r llo\rX: y\r\rClass-Path: a.jar\r",
+        );
+
+        assert_eq!(manifest.get_main_class(), Some("com.max.Hello"));
+        assert_eq!(manifest.get_attribute("X"), Some("y"));
+        assert_eq!(manifest.get_attribute("Class-Path"), None);
+    }
+
+    #[test]
+    fn ignores_case_of_attribute_names() {
+        let manifest = manifest("app.jar", "main-class: com.max.Hello\nCLASS-PATH: a.jar\n");
+
+        assert_eq!(manifest.get_main_class(), Some("com.max.Hello"));
+        assert_eq!(manifest.get_attribute("class-path"), Some("a.jar"));
+        assert_eq!(manifest.get_class_path(), vec![PathBuf::from("a.jar")]);
+    }
+
+    #[test]
+    fn resolves_class_path_against_jar_folder() {
+        let manifest = manifest(
+            "/opt/app/app.jar",
+            "Class-Path: lib/my%20lib.jar file:classes/ %zz.jar 100%.jar\n",
+        );
+
+        assert_eq!(
+            manifest.get_class_path(),
+            vec![
+                PathBuf::from("/opt/app/lib/my lib.jar"),
+                PathBuf::from("/opt/app/classes/"),
+                PathBuf::from("/opt/app/%zz.jar"),
+                PathBuf::from("/opt/app/100%.jar"),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_escaped_octets() {
+        assert_eq!(decode_url_path("my%20lib.jar"), "my lib.jar");
+        assert_eq!(decode_url_path("%C3%A9t%C3%A9.jar"), "été.jar");
+        assert_eq!(decode_url_path("%2"), "%2");
+        assert_eq!(decode_url_path("%+1"), "%+1");
+    }
+
+    #[test]
+    fn archive_without_manifest_has_no_attributes() {
+        // empty archive consists of the end of central directory record only
+        let mut data = vec![0x50, 0x4b, 0x05, 0x06];
+        data.resize(22, 0);
+
+        let jar_path = std::env::temp_dir().join(format!("no-manifest-{}.jar", std::process::id()));
+        fs::write(&jar_path, data).unwrap();
+        let manifest = JarManifest::read(&jar_path);
+        fs::remove_file(&jar_path).unwrap();
+
+        let manifest = manifest.unwrap();
+        assert_eq!(manifest.get_main_class(), None);
+        assert!(manifest.get_class_path().is_empty());
+    }
+}
@@ -55,6 +55,46 @@ impl MethodInfo {
         &self.name
     }
 
+    pub fn get_descriptor(&self) -> &str {
+        &self.descriptor
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Static)
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Public)
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Protected)
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Private)
+    }
+
+    pub fn is_native(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Native)
+    }
+
+    pub fn is_synchronized(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Synchronized)
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.access_flags.contains(&MethodAccessFlag::Abstract)
+    }
+
+    /**
+     * Instance initialization method or class initialization method
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.9
+     */
+    pub fn is_initialization(&self) -> bool {
+        self.name == "<init>" || self.name == "<clinit>"
+    }
+
     fn read_name_or_descriptor(
         data: &mut RawByteBuffer,
         constant_pool: &ConstantPool,
@@ -65,7 +105,7 @@ impl MethodInfo {
     }
 
     pub fn is_main(&self) -> bool {
-        self.name == "main"
+        self.name == "main" && self.descriptor == "([Ljava/lang/String;)V" && self.is_static()
     }
 
     pub fn get_code_attribute(&self) -> Option<&AttributeInfo> {
@@ -85,7 +125,7 @@ impl MethodInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MethodAccessFlag {
     Public = 0x0001,
     Private = 0x0002,
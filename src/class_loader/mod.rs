@@ -2,8 +2,12 @@ pub mod attribute_info;
 pub mod class_file;
 pub mod class_registry;
 pub mod constant_pool;
+pub mod descriptor;
 pub mod field_info;
+pub mod inflate;
+pub mod jar_manifest;
 pub mod method_info;
 pub mod raw_data;
+pub mod zip_archive;
 
 pub mod opcodes;
@@ -4,7 +4,11 @@ use std::io::{Error, ErrorKind};
 pub enum Opcode {
     Nop,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.aconst_null
+    AconstNull,
+
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iconst_i
+    IconstM1,
     Iconst0,
     Iconst1,
     Iconst2,
@@ -12,27 +16,245 @@ pub enum Opcode {
     Iconst4,
     Iconst5,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lconst_l
+    Lconst0,
+    Lconst1,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fconst_f
+    Fconst0,
+    Fconst1,
+    Fconst2,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dconst_d
+    Dconst0,
+    Dconst1,
+
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.bipush
     Bipush,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.sipush
+    Sipush,
+
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.aload_n
     Aload0,
     Aload1,
     Aload2,
     Aload3,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.astore_n
+    Astore0,
+    Astore1,
+    Astore2,
+    Astore3,
+
     // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.istore_n
     Istore0,
     Istore1,
     Istore2,
     Istore3,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.pop
+    Pop,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.pop2
+    Pop2,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dup
+    Dup,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dup_x1
+    DupX1,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dup_x2
+    DupX2,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dup2
+    Dup2,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dup2_x1
+    Dup2X1,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dup2_x2
+    Dup2X2,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.swap
+    Swap,
+
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iadd
     Iadd,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ladd
+    Ladd,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.isub
+    Isub,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lsub
+    Lsub,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.imul
+    Imul,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lmul
+    Lmul,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.idiv
+    Idiv,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ldiv
+    Ldiv,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.irem
+    Irem,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lrem
+    Lrem,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ineg
+    Ineg,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lneg
+    Lneg,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ishl
+    Ishl,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lshl
+    Lshl,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ishr
+    Ishr,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lshr
+    Lshr,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iushr
+    Iushr,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lushr
+    Lushr,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iand
+    Iand,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.land
+    Land,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ior
+    Ior,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lor
+    Lor,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ixor
+    Ixor,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lxor
+    Lxor,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fadd
+    Fadd,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dadd
+    Dadd,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fsub
+    Fsub,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dsub
+    Dsub,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fmul
+    Fmul,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dmul
+    Dmul,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fdiv
+    Fdiv,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ddiv
+    Ddiv,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.frem
+    Frem,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.drem
+    Drem,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fneg
+    Fneg,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dneg
+    Dneg,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.i2l
+    I2l,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.i2f
+    I2f,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.i2d
+    I2d,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.l2i
+    L2i,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.l2f
+    L2f,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.l2d
+    L2d,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.f2i
+    F2i,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.f2l
+    F2l,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.f2d
+    F2d,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.d2i
+    D2i,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.d2l
+    D2l,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.d2f
+    D2f,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.i2b
+    I2b,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.i2c
+    I2c,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.i2s
+    I2s,
+
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iinc
     Iinc,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lcmp
+    Lcmp,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fcmp_op
+    Fcmpl,
+    Fcmpg,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dcmp_op
+    Dcmpl,
+    Dcmpg,
+
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.if_cond
+    Ifeq,
+    Ifne,
+    Iflt,
+    Ifge,
+    Ifgt,
+    Ifle,
+
     // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.if_icmp_cond
     Ificmpeq,
     Ificmpne,
@@ -41,26 +263,133 @@ pub enum Opcode {
     Ificmpgt,
     Ificmple,
 
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.if_acmp_cond
+    Ifacmpeq,
+    Ifacmpne,
+
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ifnull
+    Ifnull,
+    Ifnonnull,
+
     // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.goto
     Goto,
 
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.goto_w
+    GotoW,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.tableswitch
+    Tableswitch,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lookupswitch
+    Lookupswitch,
+
     // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ireturn
     Ireturn,
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lreturn
+    Lreturn,
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.freturn
+    Freturn,
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dreturn
+    Dreturn,
+    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.areturn
+    Areturn,
     Return,
 
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.getstatic
     Getstatic,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.putstatic
+    Putstatic,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.getfield
+    Getfield,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.putfield
+    Putfield,
+
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.invokevirtual
     Invokevirtual,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.invokespecial
     Invokespecial,
 
-    // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.return
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.invokestatic
     Invokestatic,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.invokeinterface
+    Invokeinterface,
+
     // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ldc
     Ldc,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ldc_w
+    LdcW,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.ldc2_w
+    Ldc2W,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lload
+    Lload,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dload
+    Dload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fload
+    Fload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fload_n
+    Fload0,
+    Fload1,
+    Fload2,
+    Fload3,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lload_n
+    Lload0,
+    Lload1,
+    Lload2,
+    Lload3,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dload_n
+    Dload0,
+    Dload1,
+    Dload2,
+    Dload3,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lstore
+    Lstore,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dstore
+    Dstore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fstore
+    Fstore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fstore_n
+    Fstore0,
+    Fstore1,
+    Fstore2,
+    Fstore3,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lstore_n
+    Lstore0,
+    Lstore1,
+    Lstore2,
+    Lstore3,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dstore_n
+    Dstore0,
+    Dstore1,
+    Dstore2,
+    Dstore3,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iload
+    Iload,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.aload
+    Aload,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.istore
+    Istore,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.astore
+    Astore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.wide
+    Wide,
 
     //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iload_n
     Iload0,
@@ -68,7 +397,82 @@ pub enum Opcode {
     Iload2,
     Iload3,
 
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.new
     New,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iaload
+    Iaload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.laload
+    Laload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.faload
+    Faload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.daload
+    Daload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.aaload
+    Aaload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.baload
+    Baload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.caload
+    Caload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.saload
+    Saload,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.iastore
+    Iastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.lastore
+    Lastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.fastore
+    Fastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.dastore
+    Dastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.aastore
+    Aastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.bastore
+    Bastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.castore
+    Castore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.sastore
+    Sastore,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.newarray
+    Newarray,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.anewarray
+    Anewarray,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.multianewarray
+    Multianewarray,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.monitorenter
+    Monitorenter,
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.monitorexit
+    Monitorexit,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.arraylength
+    Arraylength,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.athrow
+    Athrow,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.checkcast
+    Checkcast,
+
+    //https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.instanceof
+    Instanceof,
 }
 
 /**
@@ -78,7 +482,9 @@ impl Opcode {
     pub fn from(code: u8) -> Result<Opcode, Error> {
         match code {
             0x00 => Ok(Opcode::Nop),
+            0x01 => Ok(Opcode::AconstNull),
 
+            0x02 => Ok(Opcode::IconstM1),
             0x03 => Ok(Opcode::Iconst0),
             0x04 => Ok(Opcode::Iconst1),
             0x05 => Ok(Opcode::Iconst2),
@@ -86,44 +492,214 @@ impl Opcode {
             0x07 => Ok(Opcode::Iconst4),
             0x08 => Ok(Opcode::Iconst5),
 
+            0x09 => Ok(Opcode::Lconst0),
+            0x0A => Ok(Opcode::Lconst1),
+            0x0B => Ok(Opcode::Fconst0),
+            0x0C => Ok(Opcode::Fconst1),
+            0x0D => Ok(Opcode::Fconst2),
+            0x0E => Ok(Opcode::Dconst0),
+            0x0F => Ok(Opcode::Dconst1),
+
             0x10 => Ok(Opcode::Bipush),
+            0x11 => Ok(Opcode::Sipush),
             0x12 => Ok(Opcode::Ldc),
+            0x13 => Ok(Opcode::LdcW),
+            0x14 => Ok(Opcode::Ldc2W),
+            0x15 => Ok(Opcode::Iload),
+            0x16 => Ok(Opcode::Lload),
+            0x17 => Ok(Opcode::Fload),
+            0x18 => Ok(Opcode::Dload),
+            0x19 => Ok(Opcode::Aload),
             0x1A => Ok(Opcode::Iload0),
             0x1B => Ok(Opcode::Iload1),
             0x1C => Ok(Opcode::Iload2),
             0x1D => Ok(Opcode::Iload3),
+            0x1E => Ok(Opcode::Lload0),
+            0x1F => Ok(Opcode::Lload1),
+            0x20 => Ok(Opcode::Lload2),
+            0x21 => Ok(Opcode::Lload3),
+            0x22 => Ok(Opcode::Fload0),
+            0x23 => Ok(Opcode::Fload1),
+            0x24 => Ok(Opcode::Fload2),
+            0x25 => Ok(Opcode::Fload3),
+            0x26 => Ok(Opcode::Dload0),
+            0x27 => Ok(Opcode::Dload1),
+            0x28 => Ok(Opcode::Dload2),
+            0x29 => Ok(Opcode::Dload3),
 
             0x2A => Ok(Opcode::Aload0),
             0x2B => Ok(Opcode::Aload1),
             0x2C => Ok(Opcode::Aload2),
             0x2D => Ok(Opcode::Aload3),
 
+            0x2E => Ok(Opcode::Iaload),
+            0x2F => Ok(Opcode::Laload),
+            0x30 => Ok(Opcode::Faload),
+            0x31 => Ok(Opcode::Daload),
+            0x32 => Ok(Opcode::Aaload),
+            0x33 => Ok(Opcode::Baload),
+            0x34 => Ok(Opcode::Caload),
+            0x35 => Ok(Opcode::Saload),
+
+            0x36 => Ok(Opcode::Istore),
+            0x37 => Ok(Opcode::Lstore),
+            0x38 => Ok(Opcode::Fstore),
+            0x39 => Ok(Opcode::Dstore),
+            0x3A => Ok(Opcode::Astore),
+
             0x3B => Ok(Opcode::Istore0),
             0x3C => Ok(Opcode::Istore1),
             0x3D => Ok(Opcode::Istore2),
             0x3E => Ok(Opcode::Istore3),
+            0x3F => Ok(Opcode::Lstore0),
+            0x40 => Ok(Opcode::Lstore1),
+            0x41 => Ok(Opcode::Lstore2),
+            0x42 => Ok(Opcode::Lstore3),
+            0x43 => Ok(Opcode::Fstore0),
+            0x44 => Ok(Opcode::Fstore1),
+            0x45 => Ok(Opcode::Fstore2),
+            0x46 => Ok(Opcode::Fstore3),
+            0x47 => Ok(Opcode::Dstore0),
+            0x48 => Ok(Opcode::Dstore1),
+            0x49 => Ok(Opcode::Dstore2),
+            0x4A => Ok(Opcode::Dstore3),
+
+            0x4B => Ok(Opcode::Astore0),
+            0x4C => Ok(Opcode::Astore1),
+            0x4D => Ok(Opcode::Astore2),
+            0x4E => Ok(Opcode::Astore3),
+
+            0x4F => Ok(Opcode::Iastore),
+            0x50 => Ok(Opcode::Lastore),
+            0x51 => Ok(Opcode::Fastore),
+            0x52 => Ok(Opcode::Dastore),
+            0x53 => Ok(Opcode::Aastore),
+            0x54 => Ok(Opcode::Bastore),
+            0x55 => Ok(Opcode::Castore),
+            0x56 => Ok(Opcode::Sastore),
+
+            0x57 => Ok(Opcode::Pop),
+            0x58 => Ok(Opcode::Pop2),
+            0x59 => Ok(Opcode::Dup),
+            0x5A => Ok(Opcode::DupX1),
+            0x5B => Ok(Opcode::DupX2),
+            0x5C => Ok(Opcode::Dup2),
+            0x5D => Ok(Opcode::Dup2X1),
+            0x5E => Ok(Opcode::Dup2X2),
+            0x5F => Ok(Opcode::Swap),
 
             0x60 => Ok(Opcode::Iadd),
+            0x61 => Ok(Opcode::Ladd),
+            0x62 => Ok(Opcode::Fadd),
+            0x63 => Ok(Opcode::Dadd),
+            0x64 => Ok(Opcode::Isub),
+            0x65 => Ok(Opcode::Lsub),
+            0x66 => Ok(Opcode::Fsub),
+            0x67 => Ok(Opcode::Dsub),
+            0x68 => Ok(Opcode::Imul),
+            0x69 => Ok(Opcode::Lmul),
+            0x6A => Ok(Opcode::Fmul),
+            0x6B => Ok(Opcode::Dmul),
+            0x6C => Ok(Opcode::Idiv),
+            0x6D => Ok(Opcode::Ldiv),
+            0x6E => Ok(Opcode::Fdiv),
+            0x6F => Ok(Opcode::Ddiv),
+            0x70 => Ok(Opcode::Irem),
+            0x71 => Ok(Opcode::Lrem),
+            0x72 => Ok(Opcode::Frem),
+            0x73 => Ok(Opcode::Drem),
+            0x74 => Ok(Opcode::Ineg),
+            0x75 => Ok(Opcode::Lneg),
+            0x76 => Ok(Opcode::Fneg),
+            0x77 => Ok(Opcode::Dneg),
+            0x78 => Ok(Opcode::Ishl),
+            0x79 => Ok(Opcode::Lshl),
+            0x7A => Ok(Opcode::Ishr),
+            0x7B => Ok(Opcode::Lshr),
+            0x7C => Ok(Opcode::Iushr),
+            0x7D => Ok(Opcode::Lushr),
+            0x7E => Ok(Opcode::Iand),
+            0x7F => Ok(Opcode::Land),
+            0x80 => Ok(Opcode::Ior),
+            0x81 => Ok(Opcode::Lor),
+            0x82 => Ok(Opcode::Ixor),
+            0x83 => Ok(Opcode::Lxor),
 
             0x84 => Ok(Opcode::Iinc),
 
+            0x85 => Ok(Opcode::I2l),
+            0x86 => Ok(Opcode::I2f),
+            0x87 => Ok(Opcode::I2d),
+            0x88 => Ok(Opcode::L2i),
+            0x89 => Ok(Opcode::L2f),
+            0x8A => Ok(Opcode::L2d),
+            0x8B => Ok(Opcode::F2i),
+            0x8C => Ok(Opcode::F2l),
+            0x8D => Ok(Opcode::F2d),
+            0x8E => Ok(Opcode::D2i),
+            0x8F => Ok(Opcode::D2l),
+            0x90 => Ok(Opcode::D2f),
+            0x91 => Ok(Opcode::I2b),
+            0x92 => Ok(Opcode::I2c),
+            0x93 => Ok(Opcode::I2s),
+
+            0x94 => Ok(Opcode::Lcmp),
+            0x95 => Ok(Opcode::Fcmpl),
+            0x96 => Ok(Opcode::Fcmpg),
+            0x97 => Ok(Opcode::Dcmpl),
+            0x98 => Ok(Opcode::Dcmpg),
+
+            0x99 => Ok(Opcode::Ifeq),
+            0x9A => Ok(Opcode::Ifne),
+            0x9B => Ok(Opcode::Iflt),
+            0x9C => Ok(Opcode::Ifge),
+            0x9D => Ok(Opcode::Ifgt),
+            0x9E => Ok(Opcode::Ifle),
+
             0x9F => Ok(Opcode::Ificmpeq),
             0xA0 => Ok(Opcode::Ificmpne),
             0xA1 => Ok(Opcode::Ificmplt),
             0xA2 => Ok(Opcode::Ificmpge),
             0xA3 => Ok(Opcode::Ificmpgt),
             0xA4 => Ok(Opcode::Ificmple),
+            0xA5 => Ok(Opcode::Ifacmpeq),
+            0xA6 => Ok(Opcode::Ifacmpne),
 
             0xA7 => Ok(Opcode::Goto),
 
+            0xAA => Ok(Opcode::Tableswitch),
+            0xAB => Ok(Opcode::Lookupswitch),
+
             0xAC => Ok(Opcode::Ireturn),
+            0xAD => Ok(Opcode::Lreturn),
+            0xAE => Ok(Opcode::Freturn),
+            0xAF => Ok(Opcode::Dreturn),
+            0xB0 => Ok(Opcode::Areturn),
             0xB1 => Ok(Opcode::Return),
 
             0xB2 => Ok(Opcode::Getstatic),
+            0xB3 => Ok(Opcode::Putstatic),
+            0xB4 => Ok(Opcode::Getfield),
+            0xB5 => Ok(Opcode::Putfield),
             0xB6 => Ok(Opcode::Invokevirtual),
             0xBB => Ok(Opcode::New),
             0xB7 => Ok(Opcode::Invokespecial),
             0xB8 => Ok(Opcode::Invokestatic),
+            0xB9 => Ok(Opcode::Invokeinterface),
+            0xBC => Ok(Opcode::Newarray),
+            0xBD => Ok(Opcode::Anewarray),
+            0xBE => Ok(Opcode::Arraylength),
+            0xBF => Ok(Opcode::Athrow),
+            0xC0 => Ok(Opcode::Checkcast),
+            0xC1 => Ok(Opcode::Instanceof),
+            0xC2 => Ok(Opcode::Monitorenter),
+            0xC3 => Ok(Opcode::Monitorexit),
+            0xC4 => Ok(Opcode::Wide),
+            0xC5 => Ok(Opcode::Multianewarray),
+
+            0xC6 => Ok(Opcode::Ifnull),
+            0xC7 => Ok(Opcode::Ifnonnull),
+            0xC8 => Ok(Opcode::GotoW),
 
             _ => Err(Error::new(
                 ErrorKind::InvalidData,
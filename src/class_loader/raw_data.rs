@@ -16,13 +16,13 @@ impl RawByteBuffer {
         let buf = &self.data;
 
         let value = (buf[offset] as u64) << 56
-            | (buf[offset] as u64) << 48
-            | (buf[offset] as u64) << 40
-            | (buf[offset] as u64) << 32
-            | (buf[offset] as u64) << 24
-            | (buf[offset + 1] as u64) << 16
-            | (buf[offset + 2] as u64) << 8
-            | (buf[offset + 3] as u64);
+            | (buf[offset + 1] as u64) << 48
+            | (buf[offset + 2] as u64) << 40
+            | (buf[offset + 3] as u64) << 32
+            | (buf[offset + 4] as u64) << 24
+            | (buf[offset + 5] as u64) << 16
+            | (buf[offset + 6] as u64) << 8
+            | (buf[offset + 7] as u64);
 
         self.cursor += 8;
         Ok(value)
@@ -73,6 +73,17 @@ impl RawByteBuffer {
         Ok(value)
     }
 
+    pub fn read_bytes(&mut self, length: usize) -> Result<Vec<u8>, Error> {
+        if let Some(error) = self.check_boundary(length) {
+            return Err(error);
+        }
+
+        let bytes = self.data[self.cursor..self.cursor + length].to_vec();
+        self.cursor += length;
+
+        Ok(bytes)
+    }
+
     pub fn read_string(&mut self, length: usize) -> Result<String, Error> {
         if let Some(error) = self.check_boundary(length) {
             return Err(error);
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::class_loader::inflate;
+
+/**
+ * ZIP archive, like JAR file, loaded into memory. Only stored and deflated entries
+ * are supported, ZIP64 extensions and encryption are not.
+ * https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT
+ */
+pub struct ZipArchive {
+    path: PathBuf,
+    data: Vec<u8>,
+
+    // entries found in the central directory, keyed by entry name, like 'com/max/Hello.class'
+    entries: HashMap<String, ZipEntry>,
+}
+
+struct ZipEntry {
+    compression_method: u16,
+    crc32: u32,
+    compressed_size: usize,
+    uncompressed_size: usize,
+
+    // offset of the local file header
+    header_offset: usize,
+}
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+
+const LOCAL_FILE_HEADER_SIZE: usize = 30;
+const CENTRAL_DIRECTORY_HEADER_SIZE: usize = 46;
+const END_OF_CENTRAL_DIRECTORY_SIZE: usize = 22;
+
+// archive comment follows the end of central directory record and can't be longer than this
+const MAX_COMMENT_LENGTH: usize = 0xFFFF;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+
+impl ZipArchive {
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        Self::from_data(path, fs::read(path)?)
+    }
+
+    fn from_data(path: &Path, data: Vec<u8>) -> Result<Self, Error> {
+        let mut archive = Self {
+            path: path.to_path_buf(),
+            data,
+            entries: HashMap::new(),
+        };
+
+        archive.read_central_directory()?;
+
+        Ok(archive)
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    /**
+     * Reads and decompresses entry, 'None' is returned if there is no entry with this name.
+     */
+    pub fn read(&self, name: &str) -> Result<Option<Vec<u8>>, Error> {
+        let Some(entry) = self.entries.get(name) else {
+            return Ok(None);
+        };
+
+        if self.read_u32(entry.header_offset)? != LOCAL_FILE_HEADER_SIGNATURE {
+            return Err(self.invalid_data("local file header not found"));
+        }
+
+        // local header may have different extra field than the central directory one
+        let name_length = self.read_u16(entry.header_offset + 26)? as usize;
+        let extra_length = self.read_u16(entry.header_offset + 28)? as usize;
+
+        let data_offset = entry.header_offset + LOCAL_FILE_HEADER_SIZE + name_length + extra_length;
+        let compressed = self.read_bytes(data_offset, entry.compressed_size)?;
+
+        let content = match entry.compression_method {
+            METHOD_STORED => compressed.to_vec(),
+            METHOD_DEFLATED => inflate::inflate(compressed, entry.uncompressed_size)?,
+            method => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    format!(
+                        "Entry '{}' of '{}' uses unsupported compression method {}",
+                        name,
+                        self.path.display(),
+                        method
+                    ),
+                ))
+            }
+        };
+
+        if content.len() != entry.uncompressed_size || crc32(&content) != entry.crc32 {
+            return Err(self.invalid_data(&format!("entry '{}' is corrupted", name)));
+        }
+
+        Ok(Some(content))
+    }
+
+    fn read_central_directory(&mut self) -> Result<(), Error> {
+        let end_offset = self.find_end_of_central_directory()?;
+
+        let entries_count = self.read_u16(end_offset + 10)? as usize;
+        let mut offset = self.read_u32(end_offset + 16)? as usize;
+
+        for _ in 0..entries_count {
+            if self.read_u32(offset)? != CENTRAL_DIRECTORY_SIGNATURE {
+                return Err(self.invalid_data("central directory is corrupted"));
+            }
+
+            let name_length = self.read_u16(offset + 28)? as usize;
+            let extra_length = self.read_u16(offset + 30)? as usize;
+            let comment_length = self.read_u16(offset + 32)? as usize;
+
+            let name_bytes =
+                self.read_bytes(offset + CENTRAL_DIRECTORY_HEADER_SIZE, name_length)?;
+            let name = String::from_utf8_lossy(name_bytes).into_owned();
+
+            let entry = ZipEntry {
+                compression_method: self.read_u16(offset + 10)?,
+                crc32: self.read_u32(offset + 16)?,
+                compressed_size: self.read_u32(offset + 20)? as usize,
+                uncompressed_size: self.read_u32(offset + 24)? as usize,
+                header_offset: self.read_u32(offset + 42)? as usize,
+            };
+
+            self.entries.insert(name, entry);
+
+            offset += CENTRAL_DIRECTORY_HEADER_SIZE + name_length + extra_length + comment_length;
+        }
+
+        Ok(())
+    }
+
+    /**
+     * End of central directory record is the last one in the archive,
+     * it can be followed only by the archive comment.
+     */
+    fn find_end_of_central_directory(&self) -> Result<usize, Error> {
+        if self.data.len() < END_OF_CENTRAL_DIRECTORY_SIZE {
+            return Err(self.invalid_data("file is too short"));
+        }
+
+        let last_offset = self.data.len() - END_OF_CENTRAL_DIRECTORY_SIZE;
+        let first_offset = last_offset.saturating_sub(MAX_COMMENT_LENGTH);
+
+        (first_offset..=last_offset)
+            .rev()
+            .find(|&offset| self.read_u32(offset).ok() == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE))
+            .ok_or_else(|| self.invalid_data("end of central directory not found"))
+    }
+
+    fn read_bytes(&self, offset: usize, length: usize) -> Result<&[u8], Error> {
+        offset
+            .checked_add(length)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or_else(|| self.invalid_data("unexpected end of file"))
+    }
+
+    fn read_u16(&self, offset: usize) -> Result<u16, Error> {
+        let bytes = self.read_bytes(offset, 2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&self, offset: usize) -> Result<u32, Error> {
+        let bytes = self.read_bytes(offset, 4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn invalid_data(&self, message: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid ZIP archive '{}': {}", self.path.display(), message),
+        )
+    }
+}
+
+/**
+ * CRC-32 checksum of the entry content, the same as used by ZIP and gzip.
+ */
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFFu32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB88320 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 'abc' compressed with fixed Huffman codes
+    const DEFLATED_ABC: [u8; 5] = [75, 76, 74, 6, 0];
+
+    /**
+     * Builds archive with the given entries, every entry is described by its name,
+     * compression method, stored bytes, uncompressed size and CRC-32.
+     */
+    fn build_archive(entries: &[(&str, u16, &[u8], usize, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut central_directory = Vec::new();
+
+        for &(name, method, content, size, crc) in entries {
+            let header_offset = data.len() as u32;
+
+            data.extend_from_slice(&LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+            data.extend_from_slice(&[20, 0, 0, 0]);
+            data.extend_from_slice(&method.to_le_bytes());
+            data.extend_from_slice(&[0; 4]);
+            data.extend_from_slice(&crc.to_le_bytes());
+            data.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            data.extend_from_slice(&(size as u32).to_le_bytes());
+            data.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            data.extend_from_slice(&[0; 2]);
+            data.extend_from_slice(name.as_bytes());
+            data.extend_from_slice(content);
+
+            central_directory.extend_from_slice(&CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+            central_directory.extend_from_slice(&[20, 0, 20, 0, 0, 0]);
+            central_directory.extend_from_slice(&method.to_le_bytes());
+            central_directory.extend_from_slice(&[0; 4]);
+            central_directory.extend_from_slice(&crc.to_le_bytes());
+            central_directory.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            central_directory.extend_from_slice(&(size as u32).to_le_bytes());
+            central_directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            central_directory.extend_from_slice(&[0; 12]);
+            central_directory.extend_from_slice(&header_offset.to_le_bytes());
+            central_directory.extend_from_slice(name.as_bytes());
+        }
+
+        let central_directory_offset = data.len() as u32;
+        data.extend_from_slice(&central_directory);
+
+        data.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+        data.extend_from_slice(&central_directory_offset.to_le_bytes());
+        data.extend_from_slice(&[0; 2]);
+
+        data
+    }
+
+    fn open_archive(data: Vec<u8>) -> Result<ZipArchive, Error> {
+        ZipArchive::from_data(Path::new("test.jar"), data)
+    }
+
+    #[test]
+    fn computes_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+    }
+
+    #[test]
+    fn reads_stored_and_deflated_entries() {
+        let data = build_archive(&[
+            ("a.txt", METHOD_STORED, b"hello", 5, crc32(b"hello")),
+            ("b.txt", METHOD_DEFLATED, &DEFLATED_ABC, 3, crc32(b"abc")),
+        ]);
+        let archive = open_archive(data).unwrap();
+
+        assert_eq!(archive.read("a.txt").unwrap().unwrap(), b"hello");
+        assert_eq!(archive.read("b.txt").unwrap().unwrap(), b"abc");
+        assert!(archive.read("c.txt").unwrap().is_none());
+    }
+
+    #[test]
+    fn finds_end_of_central_directory_before_comment() {
+        let mut data = build_archive(&[("a.txt", METHOD_STORED, b"hello", 5, crc32(b"hello"))]);
+        let comment = b"archive comment";
+
+        let comment_length_offset = data.len() - 2;
+        data[comment_length_offset..].copy_from_slice(&(comment.len() as u16).to_le_bytes());
+        data.extend_from_slice(comment);
+
+        let archive = open_archive(data).unwrap();
+        assert_eq!(archive.read("a.txt").unwrap().unwrap(), b"hello");
+    }
+
+    #[test]
+    fn rejects_crc_mismatch() {
+        let data = build_archive(&[("a.txt", METHOD_STORED, b"hello", 5, crc32(b"hallo"))]);
+        let archive = open_archive(data).unwrap();
+
+        let error = archive.read("a.txt").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn rejects_size_mismatch() {
+        let data = build_archive(&[("a.txt", METHOD_STORED, b"hello", 4, crc32(b"hello"))]);
+        let archive = open_archive(data).unwrap();
+
+        assert!(archive.read("a.txt").is_err());
+    }
+
+    #[test]
+    fn rejects_unsupported_compression_method() {
+        let data = build_archive(&[("a.txt", 12, b"hello", 5, crc32(b"hello"))]);
+        let archive = open_archive(data).unwrap();
+
+        let error = archive.read("a.txt").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn rejects_truncated_archive() {
+        let data = build_archive(&[("a.txt", METHOD_STORED, b"hello", 5, crc32(b"hello"))]);
+
+        for length in 0..data.len() {
+            assert!(open_archive(data[..length].to_vec()).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_entry_outside_of_archive() {
+        let mut data = build_archive(&[("a.txt", METHOD_STORED, b"hello", 5, crc32(b"hello"))]);
+
+        // compressed size in the central directory header, which follows the only local entry
+        let size_offset = LOCAL_FILE_HEADER_SIZE + "a.txt".len() + "hello".len() + 20;
+        data[size_offset..size_offset + 4].copy_from_slice(&1000u32.to_le_bytes());
+
+        let archive = open_archive(data).unwrap();
+        let error = archive.read("a.txt").unwrap_err();
+        assert!(error.to_string().contains("unexpected end of file"));
+    }
+
+    #[test]
+    fn rejects_corrupt_deflated_entry() {
+        let data = build_archive(&[("b.txt", METHOD_DEFLATED, &[0b111], 3, crc32(b"abc"))]);
+        let archive = open_archive(data).unwrap();
+
+        assert!(archive.read("b.txt").is_err());
+    }
+}
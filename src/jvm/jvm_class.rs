@@ -0,0 +1,769 @@
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+use crate::class_loader::class_file::ClassFile;
+use crate::class_loader::class_registry::LoaderId;
+use crate::class_loader::constant_pool::{ConstantPool, ConstantType, MemberRef};
+use crate::class_loader::descriptor::FieldType;
+use crate::class_loader::method_info::MethodInfo;
+use crate::jvm::jvm_type::JvmValue;
+
+/**
+ * Runtime representation of the loaded class or interface together with its
+ * resolved superclass and superinterfaces.
+ * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3
+ */
+pub struct JvmClass {
+    class_file: ClassFile,
+
+    // class is identified by its name together with the defining loader
+    defining_loader: LoaderId,
+
+    // 'None' only for java/lang/Object
+    super_class: Option<Arc<JvmClass>>,
+
+    // direct superinterfaces
+    interfaces: Vec<Arc<JvmClass>>,
+
+    // types of all instance fields slots, inherited fields go first
+    instance_fields: Vec<FieldType>,
+
+    // instance field slot for every declared field, 'None' for static fields
+    field_offsets: Vec<Option<usize>>,
+
+    // values of static fields indexed by field index, entries of instance fields stay undefined
+    static_values: Mutex<Vec<JvmValue>>,
+
+    init_state: Mutex<InitializationState>,
+
+    // virtual methods table, inherited slots keep the same position as in superclass table
+    vtable: OnceLock<Vec<JvmMethod>>,
+
+    // vtable slot for every declared method, 'None' for static, private and initialization methods
+    vtable_slots: OnceLock<Vec<Option<usize>>>,
+
+    // implementations of all superinterfaces methods keyed by method name and descriptor
+    itable: OnceLock<HashMap<String, JvmMethod>>,
+
+    // resolution results of the constant pool entries indexed the same way as the constant pool,
+    // every entry is resolved once and reused by all instructions referring to it
+    resolved_references: Vec<OnceLock<ResolvedReference>>,
+}
+
+/**
+ * Symbolic reference from the runtime constant pool resolved to a class, method or field.
+ * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3
+ */
+#[derive(Clone)]
+pub enum ResolvedReference {
+    Class(Weak<JvmClass>),
+    Method(Arc<MemberRef>, JvmMethod),
+    // slot of the field inside the object
+    InstanceField(Arc<MemberRef>, usize),
+    StaticField(Arc<MemberRef>, JvmField),
+}
+
+/**
+ * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.5
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitializationState {
+    NotInitialized,
+    // initialization is in progress by the thread with the given id
+    BeingInitialized(i64),
+    Initialized,
+    // initialization failed, class can't be used anymore
+    Erroneous,
+}
+
+/**
+ * Method declared in a particular class.
+ */
+#[derive(Clone)]
+pub struct JvmMethod {
+    class: Weak<JvmClass>,
+    method_index: usize,
+}
+
+impl JvmMethod {
+    pub fn new(class: &Arc<JvmClass>, method_index: usize) -> Self {
+        Self {
+            class: Arc::downgrade(class),
+            method_index,
+        }
+    }
+
+    pub fn get_class(&self) -> Arc<JvmClass> {
+        self.class
+            .upgrade()
+            .expect("Class can't be unloaded while its methods are in use")
+    }
+
+    pub fn get_method_index(&self) -> usize {
+        self.method_index
+    }
+}
+
+/**
+ * Field declared in a particular class.
+ */
+#[derive(Clone)]
+pub struct JvmField {
+    class: Weak<JvmClass>,
+    field_index: usize,
+}
+
+impl JvmField {
+    pub fn new(class: &Arc<JvmClass>, field_index: usize) -> Self {
+        Self {
+            class: Arc::downgrade(class),
+            field_index,
+        }
+    }
+
+    pub fn get_class(&self) -> Arc<JvmClass> {
+        self.class
+            .upgrade()
+            .expect("Class can't be unloaded while its fields are in use")
+    }
+
+    pub fn get_field_index(&self) -> usize {
+        self.field_index
+    }
+}
+
+impl JvmClass {
+    /**
+     * Creates class from parsed class file and links it with already loaded superclass and
+     * superinterfaces.
+     */
+    pub fn new(
+        class_file: ClassFile,
+        defining_loader: LoaderId,
+        super_class: Option<Arc<JvmClass>>,
+        interfaces: Vec<Arc<JvmClass>>,
+    ) -> Result<Arc<JvmClass>, Error> {
+        check_super_types(&class_file, super_class.as_deref(), &interfaces)?;
+
+        let mut instance_fields = match &super_class {
+            Some(super_class) => super_class.instance_fields.clone(),
+            None => Vec::new(),
+        };
+
+        let mut field_offsets = Vec::with_capacity(class_file.get_fields().len());
+        let mut static_values = Vec::with_capacity(class_file.get_fields().len());
+
+        // static fields are created and set to default values during preparation
+        // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.2
+        for field in class_file.get_fields() {
+            if field.is_static() {
+                field_offsets.push(None);
+                static_values.push(JvmValue::default_for(&FieldType::parse(
+                    field.get_descriptor(),
+                )?));
+            } else {
+                static_values.push(JvmValue::Undefined);
+                instance_fields.push(FieldType::parse(field.get_descriptor())?);
+                field_offsets.push(Some(instance_fields.len() - 1));
+            }
+        }
+
+        let resolved_references = (0..class_file.get_constant_pool().len())
+            .map(|_| OnceLock::new())
+            .collect();
+
+        let class = Arc::new(Self {
+            class_file,
+            defining_loader,
+            super_class,
+            interfaces,
+            instance_fields,
+            field_offsets,
+            static_values: Mutex::new(static_values),
+            init_state: Mutex::new(InitializationState::NotInitialized),
+            vtable: OnceLock::new(),
+            vtable_slots: OnceLock::new(),
+            itable: OnceLock::new(),
+            resolved_references,
+        });
+
+        class.link();
+
+        Ok(class)
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.class_file.get_class_name()
+    }
+
+    /**
+     * Returns constant pool entry resolved earlier, if any.
+     */
+    pub fn get_resolved_reference(&self, index: usize) -> Option<&ResolvedReference> {
+        self.resolved_references[index].get()
+    }
+
+    /**
+     * Remembers resolved constant pool entry. If the entry was resolved by another thread
+     * in between, the first result is kept, since resolution always gives the same result.
+     */
+    pub fn set_resolved_reference(&self, index: usize, reference: ResolvedReference) {
+        self.resolved_references[index].get_or_init(|| reference);
+    }
+
+    pub fn get_defining_loader(&self) -> LoaderId {
+        self.defining_loader
+    }
+
+    pub fn get_class_file(&self) -> &ClassFile {
+        &self.class_file
+    }
+
+    pub fn get_constant_pool(&self) -> &ConstantPool {
+        self.class_file.get_constant_pool()
+    }
+
+    pub fn get_method(&self, method_index: usize) -> &MethodInfo {
+        self.class_file.get_method(method_index)
+    }
+
+    pub fn get_super_class(&self) -> Option<&Arc<JvmClass>> {
+        self.super_class.as_ref()
+    }
+
+    pub fn get_interfaces(&self) -> &[Arc<JvmClass>] {
+        &self.interfaces
+    }
+
+    pub fn is_interface(&self) -> bool {
+        self.class_file.is_interface()
+    }
+
+    pub fn get_init_state(&self) -> InitializationState {
+        *self
+            .init_state
+            .lock()
+            .expect("Class state lock is poisoned")
+    }
+
+    pub fn set_init_state(&self, init_state: InitializationState) {
+        *self
+            .init_state
+            .lock()
+            .expect("Class state lock is poisoned") = init_state;
+    }
+
+    /**
+     * Interfaces with default methods are initialized together with implementing classes.
+     */
+    pub fn declares_default_methods(&self) -> bool {
+        self.class_file
+            .get_methods()
+            .iter()
+            .any(|method| !method.is_abstract() && !method.is_static())
+    }
+
+    pub fn get_static_value(&self, field_index: usize) -> JvmValue {
+        self.static_values
+            .lock()
+            .expect("Class state lock is poisoned")[field_index]
+    }
+
+    pub fn set_static_value(&self, field_index: usize, value: JvmValue) {
+        self.static_values
+            .lock()
+            .expect("Class state lock is poisoned")[field_index] = value;
+    }
+
+    /**
+     * Passes every reference stored in static fields to 'visitor', they are garbage collection
+     * roots. Visitor may replace the reference if the referenced object was moved.
+     */
+    pub fn for_each_static_reference(&self, mut visitor: impl FnMut(&mut usize)) {
+        let mut static_values = self
+            .static_values
+            .lock()
+            .expect("Class state lock is poisoned");
+
+        for value in static_values.iter_mut() {
+            if let JvmValue::Reference(reference) = value {
+                visitor(reference);
+            }
+        }
+    }
+
+    /**
+     * Assigns values from ConstantValue attributes to static fields, this is the first step of
+     * the class initialization. String constants are turned into java.lang.String instances
+     * by 'intern_string'.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.7.2
+     */
+    pub fn assign_constant_values(&self, mut intern_string: impl FnMut(&str) -> usize) {
+        for (field_index, field) in self.class_file.get_fields().iter().enumerate() {
+            if !field.is_static() {
+                continue;
+            }
+
+            let Some(constant_value_index) = field.get_constant_value_index() else {
+                continue;
+            };
+
+            let constant_pool = self.get_constant_pool();
+
+            let constant = constant_pool
+                .get_constant(constant_value_index as usize)
+                .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+            let value = match constant {
+                ConstantType::Integer { val } => JvmValue::Int(*val as i32),
+                ConstantType::Float { val } => JvmValue::Float(f32::from_bits(*val)),
+                ConstantType::Long { val } => JvmValue::Long(*val as i64),
+                ConstantType::Double { val } => JvmValue::Double(*val),
+                ConstantType::String { idx } => {
+                    let text = constant_pool
+                        .resolve_constant_pool_utf(*idx as usize)
+                        .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                    JvmValue::Reference(intern_string(&text))
+                }
+                other => panic!("Invalid ConstantValue attribute {:?}", other),
+            };
+
+            self.set_static_value(field_index, value);
+        }
+    }
+
+    /**
+     * Checks if 'other' is this class or one of its superclasses.
+     */
+    pub fn is_subclass_of(&self, other: &JvmClass) -> bool {
+        let mut current = Some(self);
+
+        while let Some(class) = current {
+            if std::ptr::eq(class, other) {
+                return true;
+            }
+            current = class.super_class.as_deref();
+        }
+
+        false
+    }
+
+    /**
+     * Checks if this class is 'other' class, its subclass or implements 'other' interface.
+     */
+    pub fn is_subtype_of(&self, other: &JvmClass) -> bool {
+        if !other.is_interface() {
+            return self.is_subclass_of(other);
+        }
+
+        std::ptr::eq(self, other)
+            || self
+                .get_all_superinterfaces()
+                .iter()
+                .any(|interface| std::ptr::eq(interface.as_ref(), other))
+    }
+
+    /**
+     * Types of instance fields in the order of their slots inside the object.
+     */
+    pub fn get_instance_fields(&self) -> &[FieldType] {
+        &self.instance_fields
+    }
+
+    /**
+     * Slot of the instance field inside the object, 'None' for static fields.
+     */
+    pub fn get_field_offset(&self, field_index: usize) -> Option<usize> {
+        self.field_offsets[field_index]
+    }
+
+    /**
+     * Field resolution for Fieldref constants.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.2
+     */
+    pub fn resolve_field(self: &Arc<Self>, name: &str, descriptor: &str) -> Option<JvmField> {
+        if let Some(field_index) = self.class_file.find_field(name, descriptor) {
+            return Some(JvmField::new(self, field_index));
+        }
+
+        for interface in &self.interfaces {
+            if let Some(field) = interface.resolve_field(name, descriptor) {
+                return Some(field);
+            }
+        }
+
+        self.super_class
+            .as_ref()
+            .and_then(|super_class| super_class.resolve_field(name, descriptor))
+    }
+
+    /**
+     * Method resolution for Methodref constants.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.3
+     */
+    pub fn resolve_method(self: &Arc<Self>, name: &str, descriptor: &str) -> Option<JvmMethod> {
+        let mut current = Some(Arc::clone(self));
+
+        while let Some(class) = current {
+            if let Some(method_index) = class.class_file.find_method(name, descriptor) {
+                return Some(JvmMethod::new(&class, method_index));
+            }
+            current = class.super_class.clone();
+        }
+
+        self.select_superinterface_method(name, descriptor)
+    }
+
+    /**
+     * Interface method resolution for InterfaceMethodref constants.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.4
+     */
+    pub fn resolve_interface_method(
+        self: &Arc<Self>,
+        name: &str,
+        descriptor: &str,
+    ) -> Option<JvmMethod> {
+        if let Some(method_index) = self.class_file.find_method(name, descriptor) {
+            return Some(JvmMethod::new(self, method_index));
+        }
+
+        // superclass of every interface is java/lang/Object
+        if let Some(object_method) = self.find_public_object_method(name, descriptor) {
+            return Some(object_method);
+        }
+
+        self.select_superinterface_method(name, descriptor)
+    }
+
+    /**
+     * Selects method for 'invokevirtual' and 'invokeinterface' when this class is the class of
+     * the receiver object.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.6
+     */
+    pub fn select_method(&self, resolved: &JvmMethod) -> Option<JvmMethod> {
+        let resolved_class = resolved.get_class();
+        let resolved_method = resolved_class.get_method(resolved.method_index);
+
+        if resolved_method.is_private() {
+            return Some(resolved.clone());
+        }
+
+        if resolved_class.is_interface() {
+            let key =
+                Self::method_key(resolved_method.get_name(), resolved_method.get_descriptor());
+            return self.get_itable().get(&key).cloned();
+        }
+
+        let slot = resolved_class.get_vtable_slots()[resolved.method_index]?;
+        self.get_vtable().get(slot).cloned()
+    }
+
+    /**
+     * Selects method for 'invokespecial' when this class is the class where lookup starts.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.invokespecial
+     */
+    pub fn select_special_method(
+        self: &Arc<Self>,
+        name: &str,
+        descriptor: &str,
+    ) -> Option<JvmMethod> {
+        let mut current = Some(Arc::clone(self));
+
+        while let Some(class) = current {
+            if let Some(method_index) = class.class_file.find_method(name, descriptor) {
+                if !class.get_method(method_index).is_static() {
+                    return Some(JvmMethod::new(&class, method_index));
+                }
+            }
+
+            if class.is_interface() {
+                break;
+            }
+            current = class.super_class.clone();
+        }
+
+        if self.is_interface() {
+            if let Some(object_method) = self.find_public_object_method(name, descriptor) {
+                return Some(object_method);
+            }
+        }
+
+        self.select_superinterface_method(name, descriptor)
+    }
+
+    fn link(self: &Arc<Self>) {
+        self.build_vtable();
+        self.build_itable();
+    }
+
+    /**
+     * Methods of the superclass table are replaced by the overriding methods of this class,
+     * all other virtual methods get new slots at the end of the table.
+     */
+    fn build_vtable(self: &Arc<Self>) {
+        let mut vtable = match &self.super_class {
+            Some(super_class) if !self.is_interface() => super_class.get_vtable().to_vec(),
+            _ => Vec::new(),
+        };
+
+        let methods = self.class_file.get_methods();
+        let mut vtable_slots = vec![None; methods.len()];
+
+        if !self.is_interface() {
+            for (method_index, method) in methods.iter().enumerate() {
+                if method.is_static() || method.is_private() || method.is_initialization() {
+                    continue;
+                }
+
+                let mut method_slot = None;
+
+                for (slot, entry) in vtable.iter_mut().enumerate() {
+                    let entry_class = entry.get_class();
+                    let entry_method = entry_class.get_method(entry.method_index);
+
+                    if entry_method.get_name() == method.get_name()
+                        && entry_method.get_descriptor() == method.get_descriptor()
+                        && self.can_override(&entry_class, entry_method)
+                    {
+                        *entry = JvmMethod::new(self, method_index);
+                        method_slot.get_or_insert(slot);
+                    }
+                }
+
+                vtable_slots[method_index] = Some(method_slot.unwrap_or_else(|| {
+                    vtable.push(JvmMethod::new(self, method_index));
+                    vtable.len() - 1
+                }));
+            }
+        }
+
+        let _ = self.vtable.set(vtable);
+        let _ = self.vtable_slots.set(vtable_slots);
+    }
+
+    fn build_itable(self: &Arc<Self>) {
+        let mut itable = HashMap::new();
+
+        if !self.is_interface() {
+            for interface in self.get_all_superinterfaces() {
+                for method in interface.class_file.get_methods() {
+                    if method.is_static() || method.is_private() {
+                        continue;
+                    }
+
+                    let key = Self::method_key(method.get_name(), method.get_descriptor());
+
+                    if itable.contains_key(&key) {
+                        continue;
+                    }
+
+                    if let Some(selected) =
+                        self.select_interface_method(method.get_name(), method.get_descriptor())
+                    {
+                        itable.insert(key, selected);
+                    }
+                }
+            }
+        }
+
+        let _ = self.itable.set(itable);
+    }
+
+    /**
+     * Method of this class or superclasses overriding interface method, or default method
+     * from superinterfaces.
+     */
+    fn select_interface_method(
+        self: &Arc<Self>,
+        name: &str,
+        descriptor: &str,
+    ) -> Option<JvmMethod> {
+        let mut current = Some(Arc::clone(self));
+
+        while let Some(class) = current {
+            if let Some(method_index) = class.class_file.find_method(name, descriptor) {
+                let method = class.get_method(method_index);
+
+                if !method.is_static() && !method.is_private() {
+                    return Some(JvmMethod::new(&class, method_index));
+                }
+            }
+            current = class.super_class.clone();
+        }
+
+        self.select_superinterface_method(name, descriptor)
+    }
+
+    /**
+     * Selects the only non-abstract maximally-specific superinterface method, if there is no such
+     * method any of maximally-specific methods is returned.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.3
+     */
+    fn select_superinterface_method(&self, name: &str, descriptor: &str) -> Option<JvmMethod> {
+        let candidates: Vec<(Arc<JvmClass>, usize)> = self
+            .get_all_superinterfaces()
+            .into_iter()
+            .filter_map(|interface| {
+                let method_index = interface.class_file.find_method(name, descriptor)?;
+                let method = interface.get_method(method_index);
+
+                if method.is_static() || method.is_private() {
+                    None
+                } else {
+                    Some((interface, method_index))
+                }
+            })
+            .collect();
+
+        // method is maximally-specific if no subinterface declares the same method
+        let maximally_specific: Vec<&(Arc<JvmClass>, usize)> = candidates
+            .iter()
+            .filter(|(interface, _)| {
+                !candidates.iter().any(|(other, _)| {
+                    !Arc::ptr_eq(interface, other)
+                        && other
+                            .get_all_superinterfaces()
+                            .iter()
+                            .any(|super_interface| Arc::ptr_eq(super_interface, interface))
+                })
+            })
+            .collect();
+
+        let non_abstract: Vec<&&(Arc<JvmClass>, usize)> = maximally_specific
+            .iter()
+            .filter(|(interface, method_index)| !interface.get_method(*method_index).is_abstract())
+            .collect();
+
+        if let [(interface, method_index)] = non_abstract.as_slice() {
+            return Some(JvmMethod::new(interface, *method_index));
+        }
+
+        maximally_specific
+            .first()
+            .map(|(interface, method_index)| JvmMethod::new(interface, *method_index))
+    }
+
+    fn find_public_object_method(&self, name: &str, descriptor: &str) -> Option<JvmMethod> {
+        let mut object_class = self.super_class.as_ref()?;
+
+        while let Some(super_class) = &object_class.super_class {
+            object_class = super_class;
+        }
+
+        let method_index = object_class.class_file.find_method(name, descriptor)?;
+        let method = object_class.get_method(method_index);
+
+        if method.is_public() && !method.is_static() {
+            Some(JvmMethod::new(object_class, method_index))
+        } else {
+            None
+        }
+    }
+
+    /**
+     * All superinterfaces of this class, its superclasses and superinterfaces without duplicates.
+     */
+    fn get_all_superinterfaces(&self) -> Vec<Arc<JvmClass>> {
+        let mut all_interfaces: Vec<Arc<JvmClass>> = Vec::new();
+
+        let mut pending: Vec<Arc<JvmClass>> = self.interfaces.clone();
+
+        let mut current = self.super_class.as_deref();
+        while let Some(class) = current {
+            pending.extend(class.interfaces.iter().cloned());
+            current = class.super_class.as_deref();
+        }
+
+        while let Some(interface) = pending.pop() {
+            if all_interfaces
+                .iter()
+                .any(|existing| Arc::ptr_eq(existing, &interface))
+            {
+                continue;
+            }
+
+            pending.extend(interface.interfaces.iter().cloned());
+            all_interfaces.push(interface);
+        }
+
+        all_interfaces
+    }
+
+    /**
+     * Package private methods can be overridden only from the same runtime package.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.5
+     */
+    fn can_override(&self, super_class: &JvmClass, super_method: &MethodInfo) -> bool {
+        super_method.is_public()
+            || super_method.is_protected()
+            || super_class.class_file.get_package_name() == self.class_file.get_package_name()
+    }
+
+    fn get_vtable(&self) -> &[JvmMethod] {
+        self.vtable.get().expect("Class should be linked")
+    }
+
+    fn get_vtable_slots(&self) -> &[Option<usize>] {
+        self.vtable_slots.get().expect("Class should be linked")
+    }
+
+    fn get_itable(&self) -> &HashMap<String, JvmMethod> {
+        self.itable.get().expect("Class should be linked")
+    }
+
+    fn method_key(name: &str, descriptor: &str) -> String {
+        format!("{}{}", name, descriptor)
+    }
+}
+
+/**
+ * Checks that the superclass is a class which can be extended and all superinterfaces are
+ * interfaces, these checks are done when the class is linked with its supertypes.
+ * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3.5
+ */
+fn check_super_types(
+    class_file: &ClassFile,
+    super_class: Option<&JvmClass>,
+    interfaces: &[Arc<JvmClass>],
+) -> Result<(), Error> {
+    let class_name = class_file.get_class_name().replace('/', ".");
+
+    if let Some(super_class) = super_class {
+        let super_class_name = super_class.get_name().replace('/', ".");
+
+        if super_class.is_interface() {
+            return Err(incompatible_class_change(format!(
+                "class {} has interface {} as super class",
+                class_name, super_class_name
+            )));
+        }
+
+        if super_class.class_file.is_final() {
+            return Err(incompatible_class_change(format!(
+                "class {} cannot inherit from final class {}",
+                class_name, super_class_name
+            )));
+        }
+    }
+
+    for interface in interfaces {
+        if !interface.is_interface() {
+            return Err(incompatible_class_change(format!(
+                "class {} can not implement {}, because it is not an interface",
+                class_name,
+                interface.get_name().replace('/', ".")
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn incompatible_class_change(message: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("java.lang.IncompatibleClassChangeError: {}", message),
+    )
+}
@@ -1,122 +1,4007 @@
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::mem;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
 use crate::class_loader::attribute_info::AttributeInfo;
+use crate::class_loader::class_file::ClassFile;
+use crate::class_loader::class_registry::{ClassRegistry, ClassState, LoaderId};
+use crate::class_loader::constant_pool::{ConstantType, MemberRef};
+use crate::class_loader::descriptor::{FieldType, MethodDescriptor};
 use crate::class_loader::opcodes::Opcode;
-use crate::class_loader::constant_pool::ConstantPool;
-use crate::class_loader::method_info::MethodInfo;
+use crate::class_loader::raw_data::RawByteBuffer;
+use crate::jvm::jvm_class::{
+    InitializationState, JvmClass, JvmField, JvmMethod, ResolvedReference,
+};
 use crate::jvm::jvm_frame::JvmFrame;
+use crate::jvm::jvm_gc::{CollectionKind, GcMode, GcOptions, GcStats};
+use crate::jvm::jvm_heap::JvmHeap;
+use crate::jvm::jvm_natives::{self, NativeMethod};
+use crate::jvm::jvm_thread::{JvmLock, JvmLockGuard, JvmThreads};
 use crate::jvm::jvm_type::JvmValue;
 
-pub fn execute_bytecode(method: &MethodInfo, constant_pool: &ConstantPool) {
-    if let Some(code_attribute) = method.get_code_attribute() {
-        if let AttributeInfo::Code {
-            name: _,
-            bytecode,
-            max_stack,
-            max_locals,
-            exception_table: _,
-        } = code_attribute {
-            let mut frame = JvmFrame::new(*max_stack, *max_locals);
+/**
+ * State shared by all Java threads, it's accessed only by the thread holding the VM lock.
+ */
+struct JvmRuntime {
+    class_registry: ClassRegistry,
+
+    heap: JvmHeap,
+
+    // string pool shared by all string literals and String.intern()
+    interned_strings: HashMap<String, usize>,
+
+    // java.lang.Class instance of every class or array type, keyed by class name in internal form
+    // or by array descriptor, like '[Ljava/lang/String;', and defining loader of the class
+    // or of the array element class
+    class_mirrors: HashMap<(String, LoaderId), usize>,
+
+    // java.lang.ClassLoader instances of the application and user-defined loaders
+    class_loaders: HashMap<LoaderId, usize>,
+
+    // resolved CONSTANT_MethodType and CONSTANT_MethodHandle entries
+    method_types: HashMap<String, usize>,
+    method_handles: HashMap<(u8, MemberRef), usize>,
+
+    threads: JvmThreads,
+
+    // call stacks of threads which released the VM lock, they are scanned by garbage collector
+    parked_frames: HashMap<i64, Vec<JvmFrame>>,
+}
+
+/**
+ * Executes bytecode on behalf of a single Java thread.
+ */
+pub struct JvmEngine {
+    runtime: JvmLockGuard<JvmRuntime>,
+
+    // id of the executing thread, the same as java.lang.Thread.getId()
+    thread_id: i64,
+
+    // the call stack, frame of the currently executed method is on top
+    frames: Vec<JvmFrame>,
+
+    // instructions executed since the VM lock was yielded to other threads last time
+    executed_instructions: usize,
+}
+
+/**
+ * Outcome of a single executed instruction.
+ */
+enum ExecutionResult {
+    Continue,
+    // current method completed, optionally with return value
+    Return(Option<JvmValue>),
+    // exception with the given reference was thrown by the current instruction
+    Throw(usize),
+}
+
+// number of instructions executed before other threads get a chance to run
+const TIME_SLICE_INSTRUCTIONS: usize = 10_000;
+
+impl JvmEngine {
+    /**
+     * Creates engine for the 'main' thread, heap is configured by 'gc_options'.
+     */
+    pub fn new(class_registry: ClassRegistry, gc_options: GcOptions) -> Self {
+        let runtime = JvmRuntime {
+            class_registry,
+            heap: JvmHeap::new(gc_options),
+            interned_strings: HashMap::new(),
+            class_mirrors: HashMap::new(),
+            class_loaders: HashMap::new(),
+            method_types: HashMap::new(),
+            method_handles: HashMap::new(),
+            threads: JvmThreads::default(),
+            parked_frames: HashMap::new(),
+        };
+
+        Self::attach(JvmLock::new(runtime), 0)
+    }
+
+    /**
+     * Creates engine for the thread with the given id, blocks till the VM lock is acquired.
+     */
+    fn attach(lock: &'static JvmLock<JvmRuntime>, thread_id: i64) -> Self {
+        Self {
+            runtime: JvmLockGuard::acquire(lock),
+            thread_id,
+            frames: Vec::new(),
+            executed_instructions: 0,
+        }
+    }
+
+    pub fn execute_main(&mut self, main_class_name: &str, args: &[String]) -> Result<(), Error> {
+        let main_class = self.load_class(main_class_name)?;
+
+        let main_method_index = main_class.get_class_file().main_method_index()?;
+
+        if let Err(exception) = self.start_main_thread() {
+            return Err(self.describe_uncaught_exception(exception));
+        }
+
+        let result = self
+            .run_main(main_class, main_method_index, args)
+            .map_err(|exception| self.describe_uncaught_exception(exception));
+
+        self.terminate_thread();
+
+        result
+    }
+
+    fn run_main(
+        &mut self,
+        main_class: Arc<JvmClass>,
+        main_method_index: usize,
+        args: &[String],
+    ) -> Result<(), usize> {
+        self.initialize_class(&main_class)?;
+
+        let args_array = self.runtime.heap.allocate_array(
+            FieldType::Object("java/lang/String".to_string()),
+            args.len(),
+        );
+
+        for (index, arg) in args.iter().enumerate() {
+            let arg_value = JvmValue::Reference(self.new_string(arg));
+
+            self.runtime
+                .heap
+                .get_array_mut(args_array)
+                .set_element(index, arg_value);
+            self.runtime.heap.record_write(args_array, arg_value);
+        }
+
+        self.invoke(
+            main_class,
+            main_method_index,
+            vec![JvmValue::Reference(args_array)],
+        )?;
+
+        Ok(())
+    }
+
+    /**
+     * Blocks till all non-daemon threads terminate, daemon threads are abandoned
+     * when the VM exits.
+     * https://docs.oracle.com/javase/specs/jls/se17/html/jls-12.html#jls-12.8
+     */
+    pub fn wait_for_non_daemon_threads(&mut self) {
+        while self.runtime.threads.has_non_daemon_threads() {
+            self.wait_for_change(None);
+        }
+    }
+
+    /**
+     * Creates java.lang.Thread instance named 'main' for the thread executing 'main' method.
+     */
+    fn start_main_thread(&mut self) -> Result<(), usize> {
+        let thread_class = self
+            .load_class("java/lang/Thread")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        self.initialize_class(&thread_class)?;
+
+        let init_index = thread_class
+            .get_class_file()
+            .find_method("<init>", "(Ljava/lang/String;)V")
+            .expect("java.lang.Thread(String) constructor not found");
+
+        let name = self.new_string("main");
+        let thread_object = self.runtime.heap.allocate(Arc::clone(&thread_class));
+
+        let thread_handle = self.runtime.heap.create_global_handle(thread_object);
+
+        let result = self.invoke(
+            thread_class,
+            init_index,
+            vec![
+                JvmValue::Reference(thread_object),
+                JvmValue::Reference(name),
+            ],
+        );
+
+        let thread_object = self.runtime.heap.delete_global_handle(thread_handle);
+        result?;
+
+        self.thread_id = self.get_thread_id(thread_object);
+        self.runtime
+            .threads
+            .register(self.thread_id, thread_object, false);
+
+        Ok(())
+    }
+
+    /**
+     * Starts new OS thread executing 'run' method of java.lang.Thread instance.
+     */
+    pub fn start_thread(&mut self, thread_object: usize) {
+        let thread_class = Arc::clone(self.runtime.heap.get(thread_object).get_class());
+
+        let thread_id = self.get_thread_id(thread_object);
+
+        let daemon_offset = get_instance_field_offset(&thread_class, "daemon", "Z");
+        let daemon = matches!(
+            self.runtime
+                .heap
+                .get(thread_object)
+                .get_field(daemon_offset),
+            JvmValue::Int(1)
+        );
+
+        // thread is alive as soon as it's started, even if it doesn't run yet
+        self.runtime
+            .threads
+            .register(thread_id, thread_object, daemon);
+
+        let lock = self.runtime.get_lock();
+
+        thread::Builder::new()
+            .name(format!("java-thread-{}", thread_id))
+            .spawn(move || {
+                let mut engine = JvmEngine::attach(lock, thread_id);
+                engine.run_thread();
+            })
+            .unwrap_or_else(|error| panic!("Can't spawn thread with error: {}", error));
+    }
+
+    /**
+     * Executes 'run' method of the current thread, uncaught exception is reported to
+     * the standard error and terminates the thread only.
+     */
+    fn run_thread(&mut self) {
+        let thread_object = self
+            .runtime
+            .threads
+            .get_thread_object(self.thread_id)
+            .expect("Started thread should be registered");
+
+        let run_ref = MemberRef {
+            class_name: "java/lang/Thread".to_string(),
+            name: "run".to_string(),
+            descriptor: "()V".to_string(),
+        };
+
+        let resolved = self
+            .resolve_method(&run_ref, LoaderId::Bootstrap)
+            .expect("java.lang.Thread.run() should be declared");
+        let thread_class = self.get_object_class(thread_object);
+
+        let selected = thread_class
+            .select_method(&resolved)
+            .expect("java.lang.Thread.run() should be implemented");
+
+        if let Err(exception) = self.invoke(
+            selected.get_class(),
+            selected.get_method_index(),
+            vec![JvmValue::Reference(thread_object)],
+        ) {
+            eprintln!("{}", self.describe_uncaught_exception(exception));
+        }
+
+        self.terminate_thread();
+    }
+
+    /**
+     * Removes terminated thread from the registry and wakes up threads joining it.
+     */
+    fn terminate_thread(&mut self) {
+        self.runtime.threads.remove(self.thread_id);
+        self.runtime.notify_all();
+    }
+
+    /**
+     * Returns java.lang.Thread instance of the executing thread.
+     */
+    pub fn current_thread(&self) -> Option<usize> {
+        self.runtime.threads.get_thread_object(self.thread_id)
+    }
+
+    /**
+     * Reads 'tid' field of java.lang.Thread instance.
+     */
+    pub fn get_thread_id(&self, thread_object: usize) -> i64 {
+        let thread = self.runtime.heap.get(thread_object);
+        let tid_offset = get_instance_field_offset(thread.get_class(), "tid", "J");
+
+        match thread.get_field(tid_offset) {
+            JvmValue::Long(thread_id) => thread_id,
+            other => panic!("Expected JvmValue::Long, but found {:?}", other),
+        }
+    }
+
+    pub fn is_thread_alive(&self, thread_object: usize) -> bool {
+        let thread_id = self.get_thread_id(thread_object);
+        self.runtime.threads.is_alive(thread_id)
+    }
+
+    /**
+     * Blocks till the thread terminates or 'millis' milliseconds pass, zero means forever.
+     * InterruptedException is thrown if the current thread is interrupted while waiting.
+     */
+    pub fn join_thread(&mut self, thread_object: usize, millis: i64) -> Result<(), usize> {
+        let thread_id = self.get_thread_id(thread_object);
+
+        let deadline = (millis > 0).then(|| Instant::now() + Duration::from_millis(millis as u64));
+
+        if self.block_until(deadline, |runtime| !runtime.threads.is_alive(thread_id)) {
+            return Err(self.interrupted_exception(None));
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Suspends the current thread, other threads are executed meanwhile.
+     * InterruptedException is thrown if the current thread is interrupted while sleeping.
+     */
+    pub fn sleep(&mut self, millis: i64) -> Result<(), usize> {
+        let deadline = Instant::now() + Duration::from_millis(millis as u64);
+
+        if self.block_until(Some(deadline), |_| false) {
+            return Err(self.interrupted_exception(Some("sleep interrupted")));
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Releases monitor of the object and waits till another thread notifies the object,
+     * 'millis' milliseconds pass or the current thread is interrupted, zero means forever.
+     * Monitor is locked again with the same entry count before returning.
+     * https://docs.oracle.com/javase/specs/jls/se17/html/jls-17.html#jls-17.2.1
+     */
+    pub fn wait_on_object(&mut self, object: usize, millis: i64) -> Result<(), usize> {
+        if !self.runtime.heap.owns_monitor(object, self.thread_id) {
+            return Err(self.new_exception(
+                "java/lang/IllegalMonitorStateException",
+                "current thread is not owner",
+            ));
+        }
+
+        if self.is_interrupted() {
+            return Err(self.interrupted_exception(None));
+        }
+
+        let entry_count = self.runtime.heap.release_monitor(object, self.thread_id);
+        self.runtime.heap.add_waiter(object, self.thread_id);
+
+        // object stays reachable while the thread doesn't own its monitor
+        let object_handle = self.runtime.heap.create_global_handle(object);
+
+        // threads blocked on the monitor can proceed now
+        self.runtime.notify_all();
+
+        let deadline = (millis > 0).then(|| Instant::now() + Duration::from_millis(millis as u64));
+
+        let thread_id = self.thread_id;
+        let interrupted = self.block_until(deadline, |runtime| {
+            let object = runtime.heap.get_global_handle(object_handle);
+            !runtime.heap.is_waiter(object, thread_id)
+        });
+
+        let object = self.runtime.heap.get_global_handle(object_handle);
+        self.runtime.heap.remove_waiter(object, self.thread_id);
+
+        loop {
+            let object = self.runtime.heap.get_global_handle(object_handle);
+
+            if self
+                .runtime
+                .heap
+                .reenter_monitor(object, self.thread_id, entry_count)
+            {
+                break;
+            }
+
+            self.wait_for_change(None);
+        }
+
+        self.runtime.heap.delete_global_handle(object_handle);
+
+        if interrupted {
+            return Err(self.interrupted_exception(None));
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Wakes up one or all threads waiting on the object, the current thread must own its monitor.
+     */
+    pub fn notify_object(&mut self, object: usize, all: bool) -> Result<(), usize> {
+        if !self.runtime.heap.owns_monitor(object, self.thread_id) {
+            return Err(self.new_exception(
+                "java/lang/IllegalMonitorStateException",
+                "current thread is not owner",
+            ));
+        }
+
+        self.runtime.heap.notify_waiters(object, all);
+        self.runtime.notify_all();
+
+        Ok(())
+    }
+
+    /**
+     * Wakes up all blocked threads, so the interrupted one can notice its interrupt status.
+     */
+    pub fn interrupt_thread(&mut self) {
+        self.runtime.notify_all();
+    }
+
+    /**
+     * Blocks the current thread till 'is_done' holds for the shared state or the deadline
+     * passes, the VM lock is released while waiting. 'true' is returned if the thread was
+     * interrupted before the wait completed.
+     */
+    fn block_until(
+        &mut self,
+        deadline: Option<Instant>,
+        is_done: impl Fn(&JvmRuntime) -> bool,
+    ) -> bool {
+        loop {
+            if is_done(&self.runtime) {
+                return false;
+            }
+
+            if self.is_interrupted() {
+                return true;
+            }
+
+            match deadline {
+                None => self.wait_for_change(None),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return false;
+                    }
+
+                    self.wait_for_change(Some(deadline - now));
+                }
+            }
+        }
+    }
+
+    /**
+     * Reads interrupt status of the current thread.
+     */
+    fn is_interrupted(&self) -> bool {
+        let Some(thread_object) = self.current_thread() else {
+            return false;
+        };
+
+        let thread = self.runtime.heap.get(thread_object);
+        let interrupted_offset = get_instance_field_offset(thread.get_class(), "interrupted", "Z");
+
+        matches!(thread.get_field(interrupted_offset), JvmValue::Int(1))
+    }
+
+    /**
+     * Clears interrupt status of the current thread and creates InterruptedException.
+     */
+    fn interrupted_exception(&mut self, message: Option<&str>) -> usize {
+        if let Some(thread_object) = self.current_thread() {
+            let thread = self.runtime.heap.get_mut(thread_object);
+            let interrupted_offset =
+                get_instance_field_offset(thread.get_class(), "interrupted", "Z");
+
+            thread.set_field(interrupted_offset, JvmValue::Int(0));
+        }
+
+        match message {
+            Some(message) => self.new_exception("java/lang/InterruptedException", message),
+            None => self.new_throwable("java/lang/InterruptedException", "()V", Vec::new()),
+        }
+    }
+
+    /**
+     * Gives other threads a chance to run.
+     */
+    pub fn yield_thread(&mut self) {
+        self.park_frames();
+        self.runtime.yield_lock();
+        self.unpark_frames();
+    }
+
+    /**
+     * Releases the VM lock till another thread signals about changes or 'timeout' passes.
+     */
+    fn wait_for_change(&mut self, timeout: Option<Duration>) {
+        self.park_frames();
+
+        match timeout {
+            None => self.runtime.wait(),
+            Some(timeout) => self.runtime.wait_timeout(timeout),
+        }
+
+        self.unpark_frames();
+    }
+
+    /**
+     * Moves call stack into the shared state before the VM lock is released,
+     * so garbage collection in other threads finds references held by the frames.
+     */
+    fn park_frames(&mut self) {
+        let frames = mem::take(&mut self.frames);
+        self.runtime.parked_frames.insert(self.thread_id, frames);
+    }
+
+    fn unpark_frames(&mut self) {
+        self.frames = self
+            .runtime
+            .parked_frames
+            .remove(&self.thread_id)
+            .expect("Frames of the thread should be parked");
+    }
+
+    /**
+     * Reclaims objects unreachable from the frames of all threads, static fields, interned strings
+     * and other objects referenced by the VM. Collection runs only when the current thread
+     * is between instructions and other threads are parked, so all references are known
+     * and can be updated if objects are moved.
+     */
+    pub fn collect_garbage(&mut self, kind: CollectionKind) {
+        let start = Instant::now();
+
+        match kind {
+            CollectionKind::Young => {
+                self.runtime.heap.begin_young_collection();
+
+                // static fields referring to the nursery are remembered by the heap
+                self.visit_roots(false, &mut |heap, reference| {
+                    *reference = heap.evacuate(*reference)
+                });
+
+                self.runtime.heap.finish_young_collection();
+            }
+            CollectionKind::Full => {
+                self.runtime.heap.begin_full_collection();
+                self.visit_roots(true, &mut |heap, reference| heap.mark_root(*reference));
+                self.runtime.heap.finish_marking();
+
+                match self.runtime.heap.get_mode() {
+                    GcMode::MarkSweep => self.runtime.heap.sweep(),
+                    GcMode::Generational => {
+                        self.runtime.heap.plan_compaction();
+                        self.visit_roots(true, &mut |heap, reference| {
+                            *reference = heap.forward(*reference)
+                        });
+                        self.runtime.heap.compact();
+                    }
+                }
+            }
+        }
+
+        self.runtime.heap.record_pause(kind, start.elapsed());
+    }
+
+    /**
+     * Passes every root known to the engine to 'visitor', static fields are skipped
+     * unless 'include_statics' is set.
+     */
+    fn visit_roots(
+        &mut self,
+        include_statics: bool,
+        visitor: &mut dyn FnMut(&mut JvmHeap, &mut usize),
+    ) {
+        let runtime = &mut *self.runtime;
+        let heap = &mut runtime.heap;
+
+        for frame in self
+            .frames
+            .iter_mut()
+            .chain(runtime.parked_frames.values_mut().flatten())
+        {
+            frame.for_each_reference(|reference| visitor(heap, reference));
+        }
+
+        if include_statics {
+            for class in runtime.class_registry.get_loaded_classes() {
+                class.for_each_static_reference(|reference| visitor(heap, reference));
+            }
+        }
+
+        let references = runtime
+            .interned_strings
+            .values_mut()
+            .chain(runtime.class_mirrors.values_mut())
+            .chain(runtime.class_loaders.values_mut())
+            .chain(runtime.method_types.values_mut())
+            .chain(runtime.method_handles.values_mut());
+
+        for reference in references {
+            visitor(heap, reference);
+        }
+
+        runtime
+            .threads
+            .for_each_thread_object(|reference| visitor(heap, reference));
+    }
+
+    pub fn get_gc_stats(&self) -> &GcStats {
+        self.runtime.heap.get_stats()
+    }
+
+    /**
+     * Makes room for 'size' bytes in the heap collecting garbage if needed,
+     * OutOfMemoryError is thrown if the heap limit is reached anyway.
+     */
+    fn reserve_heap(&mut self, size: usize) -> Result<(), usize> {
+        let mut last_collection = None;
+
+        while let Some(kind) = self.runtime.heap.get_due_collection(size) {
+            self.collect_garbage(kind);
+            last_collection = Some(kind);
+
+            // survivors of young collection may still not leave enough room in the nursery
+            if kind == CollectionKind::Full {
+                break;
+            }
+        }
+
+        if !self.runtime.heap.has_room_for(size) && last_collection != Some(CollectionKind::Full) {
+            self.collect_garbage(CollectionKind::Full);
+        }
+
+        if !self.runtime.heap.has_room_for(size) {
+            return Err(self.new_exception("java/lang/OutOfMemoryError", "Java heap space"));
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Creates java.lang.String instance with characters of 'text'.
+     */
+    pub fn new_string(&mut self, text: &str) -> usize {
+        let string_class = self
+            .load_class("java/lang/String")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        let chars: Vec<u16> = text.encode_utf16().collect();
+
+        let value = self
+            .runtime
+            .heap
+            .allocate_array(FieldType::Char, chars.len());
+
+        let array = self.runtime.heap.get_array_mut(value);
+        for (index, ch) in chars.into_iter().enumerate() {
+            array.set_element(index, JvmValue::Int(ch as i32));
+        }
+
+        let value_offset = get_instance_field_offset(&string_class, "value", "[C");
+
+        let string = self.runtime.heap.allocate(string_class);
+        self.runtime
+            .heap
+            .get_mut(string)
+            .set_field(value_offset, JvmValue::Reference(value));
+        self.runtime
+            .heap
+            .record_write(string, JvmValue::Reference(value));
+
+        string
+    }
+
+    /**
+     * Reads characters of java.lang.String instance.
+     */
+    pub fn get_string(&self, reference: usize) -> String {
+        let string = self.runtime.heap.get(reference);
+
+        let value_offset = get_instance_field_offset(string.get_class(), "value", "[C");
+
+        let JvmValue::Reference(value) = string.get_field(value_offset) else {
+            panic!("java.lang.String without characters array");
+        };
+
+        let array = self.runtime.heap.get_array(value);
+
+        let chars: Vec<u16> = (0..array.length())
+            .map(|index| match array.get_element(index) {
+                JvmValue::Int(ch) => ch as u16,
+                other => panic!("Expected JvmValue::Int, but found {:?}", other),
+            })
+            .collect();
+
+        String::from_utf16_lossy(&chars)
+    }
+
+    /**
+     * Copies 'length' elements of byte[] array starting from 'offset'.
+     */
+    pub fn get_byte_array_region(&self, reference: usize, offset: usize, length: usize) -> Vec<u8> {
+        let array = self.runtime.heap.get_array(reference);
+
+        (offset..offset + length)
+            .map(|index| match array.get_element(index) {
+                JvmValue::Int(byte) => byte as u8,
+                other => panic!("Expected JvmValue::Int, but found {:?}", other),
+            })
+            .collect()
+    }
+
+    /**
+     * Returns java.lang.String instance from the string pool, all string literals with the same
+     * characters refer to the same instance.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.1
+     */
+    pub fn intern_string(&mut self, text: &str) -> usize {
+        if let Some(&string) = self.runtime.interned_strings.get(text) {
+            return string;
+        }
+
+        let string = self.new_string(text);
+        self.runtime
+            .interned_strings
+            .insert(text.to_string(), string);
+
+        string
+    }
+
+    /**
+     * Returns java.lang.Class instance of the object's class, element class of the array type
+     * is loaded by the loader of the current class.
+     */
+    pub fn get_object_class_mirror(&mut self, reference: usize) -> Result<usize, usize> {
+        if self.runtime.heap.is_array(reference) {
+            let type_name = self.get_object_type_name(reference);
+            let loader = self.get_current_loader();
+            return self.get_class_mirror(&type_name, loader);
+        }
+
+        let class = Arc::clone(self.runtime.heap.get(reference).get_class());
+        Ok(self.get_type_mirror(class.get_name(), class.get_defining_loader()))
+    }
+
+    /**
+     * Returns java.lang.Class instance of the class or array type, array types are named by their
+     * descriptors. Class is loaded by the given loader, but not initialized.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.1
+     */
+    fn get_class_mirror(&mut self, class_name: &str, loader: LoaderId) -> Result<usize, usize> {
+        // element class of array type must be loaded as well
+        let element_class_name = match class_name.strip_prefix('[') {
+            Some(component) => component_class_name(component.trim_start_matches('[')),
+            None => Some(class_name),
+        };
+
+        // arrays of primitive types are defined by the bootstrap loader
+        let defining_loader = match element_class_name {
+            Some(element_class_name) => self
+                .resolve_class(element_class_name, loader)?
+                .get_defining_loader(),
+            None => LoaderId::Bootstrap,
+        };
+
+        Ok(self.get_type_mirror(class_name, defining_loader))
+    }
+
+    /**
+     * Returns java.lang.Class instance of the loaded class or array type, 'defining_loader'
+     * is the loader of the class or of the array element class.
+     */
+    fn get_type_mirror(&mut self, type_name: &str, defining_loader: LoaderId) -> usize {
+        let key = (type_name.to_string(), defining_loader);
+
+        if let Some(&mirror) = self.runtime.class_mirrors.get(&key) {
+            return mirror;
+        }
+
+        let class_class = self
+            .load_class("java/lang/Class")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        let name = self.new_string(&type_name.replace('/', "."));
+        let name_offset = get_instance_field_offset(&class_class, "name", "Ljava/lang/String;");
+        let loader_offset = get_instance_field_offset(&class_class, "classLoaderId", "I");
+
+        let mirror = self.runtime.heap.allocate(class_class);
+        let mirror_object = self.runtime.heap.get_mut(mirror);
+
+        mirror_object.set_field(name_offset, JvmValue::Reference(name));
+        mirror_object.set_field(loader_offset, JvmValue::Int(defining_loader.get_id()));
+
+        self.runtime
+            .heap
+            .record_write(mirror, JvmValue::Reference(name));
+
+        self.runtime.class_mirrors.insert(key, mirror);
+
+        mirror
+    }
+
+    /**
+     * Name of the type represented by java.lang.Class instance in internal form
+     * and its defining loader.
+     */
+    fn get_mirror_type(&self, mirror: usize) -> (String, LoaderId) {
+        let mirror_object = self.runtime.heap.get(mirror);
+        let class_class = mirror_object.get_class();
+
+        let name_offset = get_instance_field_offset(class_class, "name", "Ljava/lang/String;");
+        let loader_offset = get_instance_field_offset(class_class, "classLoaderId", "I");
+
+        let type_name = match mirror_object.get_field(name_offset) {
+            JvmValue::Reference(name) => self.get_string(name).replace('.', "/"),
+            other => panic!("Expected class name, but found {:?}", other),
+        };
+
+        let loader = match mirror_object.get_field(loader_offset) {
+            JvmValue::Int(id) => LoaderId::from_id(id),
+            other => panic!("Expected loader id, but found {:?}", other),
+        };
+
+        (type_name, loader)
+    }
+
+    /**
+     * Class represented by java.lang.Class instance, 'None' is returned for array types.
+     */
+    fn get_mirror_class(&self, mirror: usize) -> Option<Arc<JvmClass>> {
+        let (type_name, loader) = self.get_mirror_type(mirror);
+
+        match self
+            .runtime
+            .class_registry
+            .get_class_state(&type_name, loader)
+        {
+            Some(ClassState::Loaded(class)) => Some(Arc::clone(class)),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns java.lang.invoke.MethodType instance for the method descriptor.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.5
+     */
+    fn get_method_type(&mut self, descriptor: &str) -> usize {
+        if let Some(&method_type) = self.runtime.method_types.get(descriptor) {
+            return method_type;
+        }
+
+        MethodDescriptor::parse(descriptor)
+            .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+        let method_type_class = self
+            .load_class("java/lang/invoke/MethodType")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        let descriptor_string = self.new_string(descriptor);
+        let descriptor_offset =
+            get_instance_field_offset(&method_type_class, "descriptor", "Ljava/lang/String;");
+
+        let method_type = self.runtime.heap.allocate(method_type_class);
+        self.runtime
+            .heap
+            .get_mut(method_type)
+            .set_field(descriptor_offset, JvmValue::Reference(descriptor_string));
+        self.runtime
+            .heap
+            .record_write(method_type, JvmValue::Reference(descriptor_string));
+
+        self.runtime
+            .method_types
+            .insert(descriptor.to_string(), method_type);
+
+        method_type
+    }
+
+    /**
+     * Resolves field or method referenced by method handle and creates java.lang.invoke.MethodHandle
+     * instance with the type derived from the reference kind. Resolution error is returned
+     * as an exception instance.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.5
+     */
+    fn get_method_handle(
+        &mut self,
+        reference_kind: u8,
+        member_ref: &MemberRef,
+        loader: LoaderId,
+    ) -> Result<usize, usize> {
+        let key = (reference_kind, member_ref.clone());
+
+        if let Some(&method_handle) = self.runtime.method_handles.get(&key) {
+            return Ok(method_handle);
+        }
+
+        let owner = format!("L{};", member_ref.class_name);
+        let descriptor = &member_ref.descriptor;
+
+        let type_descriptor = match reference_kind {
+            // REF_getField, REF_getStatic, REF_putField, REF_putStatic
+            1..=4 => {
+                self.resolve_field(member_ref, loader)?;
+
+                match reference_kind {
+                    1 => format!("({}){}", owner, descriptor),
+                    2 => format!("(){}", descriptor),
+                    3 => format!("({}{})V", owner, descriptor),
+                    _ => format!("({})V", descriptor),
+                }
+            }
+            // REF_invokeVirtual, REF_invokeStatic, REF_invokeSpecial,
+            // REF_newInvokeSpecial, REF_invokeInterface
+            5..=9 => {
+                self.resolve_method(member_ref, loader)?;
+
+                let (parameters, return_type) = descriptor
+                    .strip_prefix('(')
+                    .and_then(|rest| rest.split_once(')'))
+                    .unwrap_or_else(|| panic!("Invalid method descriptor '{}'", descriptor));
+
+                match reference_kind {
+                    6 => descriptor.to_string(),
+                    8 => format!("({}){}", parameters, owner),
+                    _ => format!("({}{}){}", owner, parameters, return_type),
+                }
+            }
+            _ => panic!("Invalid method handle reference kind {}", reference_kind),
+        };
+
+        let method_type = self.get_method_type(&type_descriptor);
+        let reference_class = self.get_class_mirror(&member_ref.class_name, loader)?;
+        let reference_name = self.new_string(&member_ref.name);
+
+        let method_handle_class = self
+            .load_class("java/lang/invoke/MethodHandle")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        let fields = [
+            ("referenceKind", "I", JvmValue::Int(reference_kind as i32)),
+            (
+                "referenceClass",
+                "Ljava/lang/Class;",
+                JvmValue::Reference(reference_class),
+            ),
+            (
+                "referenceName",
+                "Ljava/lang/String;",
+                JvmValue::Reference(reference_name),
+            ),
+            (
+                "type",
+                "Ljava/lang/invoke/MethodType;",
+                JvmValue::Reference(method_type),
+            ),
+        ];
+
+        let method_handle = self.runtime.heap.allocate(Arc::clone(&method_handle_class));
+
+        for (name, field_descriptor, value) in fields {
+            let offset = get_instance_field_offset(&method_handle_class, name, field_descriptor);
+            self.runtime
+                .heap
+                .get_mut(method_handle)
+                .set_field(offset, value);
+            self.runtime.heap.record_write(method_handle, value);
+        }
+
+        self.runtime.method_handles.insert(key, method_handle);
+
+        Ok(method_handle)
+    }
+
+    /**
+     * Resolves loadable constant used by 'ldc' and 'ldc_w' instructions, resolution error
+     * is returned as an exception instance.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-4.html#jvms-4.4-310
+     */
+    fn load_constant(&mut self, class: &Arc<JvmClass>, index: usize) -> Result<JvmValue, usize> {
+        let constant_pool = class.get_constant_pool();
+
+        let resolve_utf = |utf_index: u16| {
+            constant_pool
+                .resolve_constant_pool_utf(utf_index as usize)
+                .unwrap_or_else(|error| panic!("Failed with error: {}", error))
+        };
+
+        let constant = constant_pool
+            .get_constant(index)
+            .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+        let value = match constant {
+            ConstantType::Integer { val } => JvmValue::Int(*val as i32),
+            ConstantType::Float { val } => JvmValue::Float(f32::from_bits(*val)),
+            ConstantType::String { idx } => {
+                JvmValue::Reference(self.intern_string(&resolve_utf(*idx)))
+            }
+            ConstantType::Class { name_index } => {
+                let class_name = resolve_utf(*name_index);
+                JvmValue::Reference(
+                    self.get_class_mirror(&class_name, class.get_defining_loader())?,
+                )
+            }
+            ConstantType::MethodType { descriptor_index } => {
+                JvmValue::Reference(self.get_method_type(&resolve_utf(*descriptor_index)))
+            }
+            ConstantType::MethodHandle {
+                reference_kind,
+                reference_index,
+            } => {
+                let member_ref = constant_pool
+                    .resolve_member_ref(*reference_index as usize)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                JvmValue::Reference(self.get_method_handle(
+                    *reference_kind,
+                    &member_ref,
+                    class.get_defining_loader(),
+                )?)
+            }
+            other => panic!("'ldc' can't load constant {:?}", other),
+        };
+
+        Ok(value)
+    }
+
+    /**
+     * Registers references among the values as garbage collection roots, so they stay reachable
+     * while the VM executes Java code or waits. 'None' is returned for non-reference values.
+     */
+    fn create_handles(&mut self, values: &[JvmValue]) -> Vec<Option<usize>> {
+        values
+            .iter()
+            .map(|value| match value {
+                JvmValue::Reference(reference) => {
+                    Some(self.runtime.heap.create_global_handle(*reference))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /**
+     * Unregisters handles created by 'create_handles', references are read back into the values.
+     */
+    fn delete_handles(&mut self, values: &mut [JvmValue], handles: Vec<Option<usize>>) {
+        for (value, handle) in values.iter_mut().zip(handles) {
+            if let Some(handle) = handle {
+                *value = JvmValue::Reference(self.runtime.heap.delete_global_handle(handle));
+            }
+        }
+    }
+
+    /**
+     * Creates exception of the given class with the detail message.
+     * Exception thrown while the new exception is created is returned instead.
+     */
+    pub fn new_exception(&mut self, class_name: &str, message: &str) -> usize {
+        let message = self.new_string(message);
+
+        self.new_throwable(
+            class_name,
+            "(Ljava/lang/String;)V",
+            vec![JvmValue::Reference(message)],
+        )
+    }
+
+    /**
+     * Creates throwable of the given class using constructor with the given arguments.
+     */
+    fn new_throwable(
+        &mut self,
+        class_name: &str,
+        descriptor: &str,
+        arguments: Vec<JvmValue>,
+    ) -> usize {
+        let class = self
+            .load_class(class_name)
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        let mut arguments = arguments;
+
+        let argument_handles = self.create_handles(&arguments);
+        let initialized = self.initialize_class(&class);
+        self.delete_handles(&mut arguments, argument_handles);
+
+        if let Err(exception) = initialized {
+            return exception;
+        }
+
+        let init_index = class
+            .get_class_file()
+            .find_method("<init>", descriptor)
+            .unwrap_or_else(|| panic!("NoSuchMethodError: '{}.<init>{}'", class_name, descriptor));
+
+        let throwable = self.runtime.heap.allocate(Arc::clone(&class));
+        let throwable_handle = self.runtime.heap.create_global_handle(throwable);
+
+        let mut init_arguments = vec![JvmValue::Reference(throwable)];
+        init_arguments.extend(arguments);
+
+        let result = self.invoke(class, init_index, init_arguments);
+        let throwable = self.runtime.heap.delete_global_handle(throwable_handle);
+
+        match result {
+            Ok(_) => throwable,
+            Err(exception) => exception,
+        }
+    }
+
+    fn throw_exception(&mut self, class_name: &str, message: &str) -> ExecutionResult {
+        ExecutionResult::Throw(self.new_exception(class_name, message))
+    }
+
+    /**
+     * Creates AbstractMethodError for the abstract method selected for invocation.
+     */
+    fn new_abstract_method_error(&mut self, class: &JvmClass, method_index: usize) -> usize {
+        let method = class.get_method(method_index);
+
+        self.new_exception(
+            "java/lang/AbstractMethodError",
+            &format!(
+                "'{}.{}{}'",
+                class.get_name(),
+                method.get_name(),
+                method.get_descriptor()
+            ),
+        )
+    }
+
+    /**
+     * Describes exception which terminated the program in the same way as Java launcher does,
+     * causes are listed on separate lines.
+     */
+    fn describe_uncaught_exception(&self, exception: usize) -> Error {
+        let thread_name = match self.current_thread() {
+            Some(thread_object) => {
+                let thread = self.runtime.heap.get(thread_object);
+                let name_offset =
+                    get_instance_field_offset(thread.get_class(), "name", "Ljava/lang/String;");
+
+                match thread.get_field(name_offset) {
+                    JvmValue::Reference(name) => self.get_string(name),
+                    _ => "main".to_string(),
+                }
+            }
+            None => "main".to_string(),
+        };
+
+        Error::other(format!(
+            "Exception in thread \"{}\" {}",
+            thread_name,
+            self.describe_exception(exception)
+        ))
+    }
+
+    /**
+     * Class name and message of the exception followed by its causes on separate lines.
+     */
+    fn describe_exception(&self, exception: usize) -> String {
+        let mut description = String::new();
+
+        let mut current = Some(exception);
+
+        while let Some(reference) = current {
+            let throwable = self.runtime.heap.get(reference);
+            let class = throwable.get_class();
+
+            if reference != exception {
+                description.push_str("\nCaused by: ");
+            }
+
+            description.push_str(&class.get_name().replace('/', "."));
+
+            let message_offset =
+                get_instance_field_offset(class, "detailMessage", "Ljava/lang/String;");
+
+            if let JvmValue::Reference(message) = throwable.get_field(message_offset) {
+                description.push_str(": ");
+                description.push_str(&self.get_string(message));
+            }
+
+            let cause_offset = get_instance_field_offset(class, "cause", "Ljava/lang/Throwable;");
+
+            current = match throwable.get_field(cause_offset) {
+                JvmValue::Reference(cause) if cause != reference => Some(cause),
+                _ => None,
+            };
+        }
+
+        description
+    }
+
+    /**
+     * Loads class together with all its superclasses and superinterfaces with the application
+     * loader, class loaded already is returned as is.
+     */
+    fn load_class(&mut self, class_name: &str) -> Result<Arc<JvmClass>, Error> {
+        self.runtime
+            .class_registry
+            .load_class(class_name, LoaderId::Application, self.thread_id)
+    }
+
+    /**
+     * Loads class with the given initiating loader. User-defined loader is asked to load the class
+     * by its 'loadClass' method, so Java code may run and objects may be moved by garbage
+     * collector. Loading error is returned as LinkageError instance.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3.2
+     */
+    fn load_class_by(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+    ) -> Result<Arc<JvmClass>, usize> {
+        loop {
+            // class loaded by the user-defined loader already is found without running Java code
+            let found = match loader {
+                LoaderId::User(_) => {
+                    self.runtime
+                        .class_registry
+                        .find_class(class_name, loader, self.thread_id)
+                }
+                _ => self
+                    .runtime
+                    .class_registry
+                    .load_class(class_name, loader, self.thread_id)
+                    .map(Some),
+            };
+
+            match found {
+                Ok(Some(class)) => return Ok(class),
+                Ok(None) => break,
+                // another thread runs Java code to load superclass of the class, wait till it's done
+                Err(error) if error.kind() == ErrorKind::WouldBlock => self.wait_for_change(None),
+                Err(error) => return Err(self.new_linkage_error(&error)),
+            }
+        }
+
+        let loader_object = *self
+            .runtime
+            .class_loaders
+            .get(&loader)
+            .expect("User-defined loader should be registered");
+
+        let load_class_ref = MemberRef {
+            class_name: "java/lang/ClassLoader".to_string(),
+            name: "loadClass".to_string(),
+            descriptor: "(Ljava/lang/String;)Ljava/lang/Class;".to_string(),
+        };
+
+        let resolved = self.resolve_method(&load_class_ref, LoaderId::Bootstrap)?;
+        let selected = self
+            .get_object_class(loader_object)
+            .select_method(&resolved)
+            .expect("java.lang.ClassLoader.loadClass(String) should be implemented");
+
+        let name = self.new_string(&class_name.replace('/', "."));
+
+        let loaded = match self.invoke(
+            selected.get_class(),
+            selected.get_method_index(),
+            vec![
+                JvmValue::Reference(loader_object),
+                JvmValue::Reference(name),
+            ],
+        ) {
+            Ok(Some(JvmValue::Reference(mirror))) => self.get_mirror_class(mirror),
+            Ok(_) => None,
+            // class which is not found is reported as NoClassDefFoundError below
+            Err(exception)
+                if self.runtime.heap.get(exception).get_class().get_name()
+                    == "java/lang/ClassNotFoundException" =>
+            {
+                None
+            }
+            Err(exception) => return Err(exception),
+        };
+
+        match loaded {
+            Some(class) if class.get_name() == class_name => {
+                self.runtime
+                    .class_registry
+                    .record_initiating_loader(loader, &class);
+                Ok(class)
+            }
+            _ => Err(self.new_exception("java/lang/NoClassDefFoundError", class_name)),
+        }
+    }
+
+    /**
+     * Loads class referenced symbolically by the class defined by 'loader', LinkageError
+     * instance is returned if the class can't be loaded, so the resolving instruction throws it.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.1
+     */
+    fn resolve_class(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+    ) -> Result<Arc<JvmClass>, usize> {
+        self.load_class_by(class_name, loader)
+    }
+
+    /**
+     * Creates instance of LinkageError subclass named by the loading error, like
+     * 'java.lang.ClassCircularityError: A', other errors are reported by NoClassDefFoundError.
+     */
+    fn new_linkage_error(&mut self, error: &Error) -> usize {
+        let description = error.to_string();
+
+        match description
+            .strip_prefix("java.lang.")
+            .and_then(|rest| rest.split_once(": "))
+        {
+            Some((class_name, message)) => {
+                self.new_exception(&format!("java/lang/{}", class_name), message)
+            }
+            None => self.new_exception("java/lang/NoClassDefFoundError", &description),
+        }
+    }
+
+    /**
+     * Creates class defined by the loader from the class file bytes, superclass and
+     * superinterfaces are loaded by the same loader. Returns java.lang.Class instance
+     * of the new class.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.3.5
+     */
+    pub fn define_class(
+        &mut self,
+        loader_object: usize,
+        expected_name: Option<&str>,
+        data: Vec<u8>,
+    ) -> Result<usize, usize> {
+        let loader = self.get_loader_id(loader_object);
+
+        let class_file = match ClassFile::new(&mut RawByteBuffer { cursor: 0, data }) {
+            Ok(class_file) => class_file,
+            Err(error) => {
+                return Err(self.new_exception("java/lang/ClassFormatError", &error.to_string()))
+            }
+        };
+
+        let class_name = class_file.get_class_name().to_string();
+
+        if let Some(expected_name) = expected_name {
+            if expected_name.replace('.', "/") != class_name {
+                return Err(self.new_exception(
+                    "java/lang/NoClassDefFoundError",
+                    &format!("{} (wrong name: {})", expected_name, class_name),
+                ));
+            }
+        }
+
+        loop {
+            match self
+                .runtime
+                .class_registry
+                .start_definition(&class_name, loader, self.thread_id)
+            {
+                Ok(()) => break,
+                // the same class is defined by another thread, which may still fail
+                Err(error) if error.kind() == ErrorKind::WouldBlock => self.wait_for_change(None),
+                Err(error) => return Err(self.new_linkage_error(&error)),
+            }
+        }
+
+        let result = self.create_class(class_file, loader);
+
+        self.runtime
+            .class_registry
+            .finish_definition(&class_name, loader, result.as_ref().ok());
+
+        // threads waiting for the class to be loaded can continue
+        self.runtime.notify_all();
+
+        let class = result?;
+
+        Ok(self.get_type_mirror(class.get_name(), loader))
+    }
+
+    fn create_class(
+        &mut self,
+        class_file: ClassFile,
+        loader: LoaderId,
+    ) -> Result<Arc<JvmClass>, usize> {
+        let super_class = match class_file.get_super_class_name() {
+            Some(super_class_name) => Some(self.load_class_by(super_class_name, loader)?),
+            None => None,
+        };
+
+        let interface_names = match class_file.get_interface_names() {
+            Ok(interface_names) => interface_names,
+            Err(error) => {
+                return Err(self.new_exception("java/lang/ClassFormatError", &error.to_string()))
+            }
+        };
+
+        let mut interfaces = Vec::new();
+        for interface_name in interface_names {
+            interfaces.push(self.load_class_by(&interface_name, loader)?);
+        }
+
+        JvmClass::new(class_file, loader, super_class, interfaces)
+            .map_err(|error| self.new_linkage_error(&error))
+    }
+
+    /**
+     * Returns java.lang.Class instance of the class loaded by the loader already, either as
+     * defining or as initiating loader.
+     */
+    pub fn find_loaded_class(&mut self, loader_object: usize, class_name: &str) -> Option<usize> {
+        let loader = self.get_loader_id(loader_object);
+
+        let class = match self
+            .runtime
+            .class_registry
+            .get_class_state(&class_name.replace('.', "/"), loader)
+        {
+            Some(ClassState::Loaded(class)) => Arc::clone(class),
+            _ => return None,
+        };
+
+        Some(self.get_type_mirror(class.get_name(), class.get_defining_loader()))
+    }
+
+    /**
+     * Loads class from the class path of the bootstrap or application loader, 'None' is returned
+     * if there is no such class.
+     */
+    pub fn find_builtin_class(
+        &mut self,
+        class_name: &str,
+        loader: LoaderId,
+    ) -> Result<Option<usize>, usize> {
+        match self
+            .runtime
+            .class_registry
+            .find_class(class_name, loader, self.thread_id)
+        {
+            Ok(Some(class)) => Ok(Some(
+                self.get_type_mirror(class.get_name(), class.get_defining_loader()),
+            )),
+            Ok(None) => Ok(None),
+            Err(error) => Err(self.new_linkage_error(&error)),
+        }
+    }
+
+    /**
+     * Registers java.lang.ClassLoader instance and returns its id, the system class loader
+     * is the only instance of the application loader.
+     */
+    pub fn register_class_loader(&mut self, loader_object: usize, system: bool) -> i32 {
+        let loader = if system {
+            LoaderId::Application
+        } else {
+            self.runtime.class_registry.register_user_loader()
+        };
+
+        self.runtime.class_loaders.insert(loader, loader_object);
+
+        loader.get_id()
+    }
+
+    /**
+     * Returns loader of the application classes, it's created when requested for the first time.
+     */
+    pub fn get_system_class_loader(&mut self) -> Result<usize, usize> {
+        if let Some(&loader_object) = self.runtime.class_loaders.get(&LoaderId::Application) {
+            return Ok(loader_object);
+        }
+
+        let loader_class = self
+            .load_class("java/lang/ClassLoader$AppClassLoader")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        self.initialize_class(&loader_class)?;
+
+        let init_index = loader_class
+            .get_class_file()
+            .find_method("<init>", "()V")
+            .expect("System class loader constructor not found");
+
+        let loader_object = self.runtime.heap.allocate(Arc::clone(&loader_class));
+
+        self.invoke(
+            loader_class,
+            init_index,
+            vec![JvmValue::Reference(loader_object)],
+        )?;
+
+        // constructor registers the loader, the object may be moved since then
+        Ok(self.runtime.class_loaders[&LoaderId::Application])
+    }
+
+    /**
+     * Returns java.lang.ClassLoader instance which defined the class represented by
+     * java.lang.Class instance, 'null' is returned for the bootstrap loader.
+     */
+    pub fn get_class_loader(&mut self, mirror: usize) -> Result<JvmValue, usize> {
+        let (_, loader) = self.get_mirror_type(mirror);
+
+        match loader {
+            LoaderId::Bootstrap => Ok(JvmValue::Null),
+            LoaderId::Application => Ok(JvmValue::Reference(self.get_system_class_loader()?)),
+            LoaderId::User(_) => Ok(JvmValue::Reference(self.runtime.class_loaders[&loader])),
+        }
+    }
+
+    /**
+     * Creates instance of the class represented by java.lang.Class instance using
+     * its no-arg constructor, class is initialized first.
+     */
+    pub fn new_instance(&mut self, mirror: usize) -> Result<usize, usize> {
+        let (type_name, _) = self.get_mirror_type(mirror);
+
+        let instantiable_class = self
+            .get_mirror_class(mirror)
+            .filter(|class| !class.is_interface() && !class.get_class_file().is_abstract());
+
+        let Some(class) = instantiable_class else {
+            return Err(self.new_exception(
+                "java/lang/InstantiationException",
+                &type_name.replace('/', "."),
+            ));
+        };
+
+        self.initialize_class(&class)?;
+
+        let Some(init_index) = class.get_class_file().find_method("<init>", "()V") else {
+            return Err(self.new_exception(
+                "java/lang/InstantiationException",
+                &type_name.replace('/', "."),
+            ));
+        };
+
+        self.reserve_heap(JvmHeap::entry_size(class.get_instance_fields().len()))?;
+
+        let object = self.runtime.heap.allocate(Arc::clone(&class));
+        let object_handle = self.runtime.heap.create_global_handle(object);
+
+        let result = self.invoke(class, init_index, vec![JvmValue::Reference(object)]);
+        let object = self.runtime.heap.delete_global_handle(object_handle);
+
+        result?;
+
+        Ok(object)
+    }
+
+    /**
+     * Reads id of the loader stored by java.lang.ClassLoader instance.
+     */
+    fn get_loader_id(&self, loader_object: usize) -> LoaderId {
+        let object = self.runtime.heap.get(loader_object);
+        let id_offset = get_instance_field_offset(object.get_class(), "id", "I");
+
+        match object.get_field(id_offset) {
+            JvmValue::Int(id) => LoaderId::from_id(id),
+            other => panic!("Expected loader id, but found {:?}", other),
+        }
+    }
+
+    /**
+     * Defining loader of the class executing the current method, application loader is used
+     * when no method is executed.
+     */
+    fn get_current_loader(&self) -> LoaderId {
+        self.frames.last().map_or(LoaderId::Application, |frame| {
+            frame.class.get_defining_loader()
+        })
+    }
+
+    /**
+     * Resolves CONSTANT_Class entry of the class constant pool, resolved class is cached
+     * by the class, so the entry is resolved only once.
+     */
+    fn resolve_class_ref(
+        &mut self,
+        class: &Arc<JvmClass>,
+        index: usize,
+    ) -> Result<Arc<JvmClass>, usize> {
+        if let Some(ResolvedReference::Class(resolved)) = class.get_resolved_reference(index) {
+            return Ok(resolved
+                .upgrade()
+                .expect("Class can't be unloaded while it's referenced"));
+        }
+
+        let class_name = class
+            .get_constant_pool()
+            .resolve_constant_pool_utf(index)
+            .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+        let resolved = self.resolve_class(&class_name, class.get_defining_loader())?;
+
+        class.set_resolved_reference(index, ResolvedReference::Class(Arc::downgrade(&resolved)));
+
+        Ok(resolved)
+    }
+
+    /**
+     * Resolves CONSTANT_Class entry referring to class or array type, for array types
+     * only the element class is loaded.
+     */
+    fn resolve_type_ref(
+        &mut self,
+        class: &Arc<JvmClass>,
+        index: usize,
+        type_name: &str,
+    ) -> Result<(), usize> {
+        match type_name.strip_prefix('[') {
+            Some(component) => {
+                if let Some(element_class_name) =
+                    component_class_name(component.trim_start_matches('['))
+                {
+                    self.resolve_class(element_class_name, class.get_defining_loader())?;
+                }
+            }
+            None => {
+                self.resolve_class_ref(class, index)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Resolves CONSTANT_Methodref or CONSTANT_InterfaceMethodref entry of the class constant pool,
+     * resolved method is cached by the class.
+     */
+    fn resolve_method_ref(
+        &mut self,
+        class: &Arc<JvmClass>,
+        index: usize,
+    ) -> Result<(Arc<MemberRef>, JvmMethod), usize> {
+        if let Some(ResolvedReference::Method(method_ref, method)) =
+            class.get_resolved_reference(index)
+        {
+            return Ok((Arc::clone(method_ref), method.clone()));
+        }
+
+        let method_ref = Arc::new(read_member_ref(class, index));
+        let method = self.resolve_method(&method_ref, class.get_defining_loader())?;
+
+        class.set_resolved_reference(
+            index,
+            ResolvedReference::Method(Arc::clone(&method_ref), method.clone()),
+        );
+
+        Ok((method_ref, method))
+    }
+
+    /**
+     * Resolves method reference of the invoke instruction which popped 'arguments' from
+     * the operand stack already. Arguments are kept reachable while the class loader runs
+     * Java code, references are updated if the objects are moved.
+     */
+    fn resolve_invoked_method_ref(
+        &mut self,
+        class: &Arc<JvmClass>,
+        index: usize,
+        arguments: &mut [JvmValue],
+    ) -> Result<JvmMethod, usize> {
+        if let Some(ResolvedReference::Method(_, method)) = class.get_resolved_reference(index) {
+            return Ok(method.clone());
+        }
+
+        let argument_handles = self.create_handles(arguments);
+        let resolved = self.resolve_method_ref(class, index);
+        self.delete_handles(arguments, argument_handles);
+
+        resolved.map(|(_, method)| method)
+    }
+
+    /**
+     * Resolves CONSTANT_Fieldref entry of the class constant pool referring to an instance field,
+     * slot of the field inside the object is cached by the class.
+     */
+    fn resolve_instance_field_ref(
+        &mut self,
+        class: &Arc<JvmClass>,
+        index: usize,
+    ) -> Result<(Arc<MemberRef>, usize), usize> {
+        if let Some(ResolvedReference::InstanceField(field_ref, offset)) =
+            class.get_resolved_reference(index)
+        {
+            return Ok((Arc::clone(field_ref), *offset));
+        }
+
+        let field_ref = Arc::new(read_member_ref(class, index));
+        let offset = self.resolve_instance_field(&field_ref, class.get_defining_loader())?;
+
+        class.set_resolved_reference(
+            index,
+            ResolvedReference::InstanceField(Arc::clone(&field_ref), offset),
+        );
+
+        Ok((field_ref, offset))
+    }
+
+    /**
+     * Resolves CONSTANT_Fieldref entry of the class constant pool referring to a static field,
+     * resolved field is cached by the class. Declaring class of the field is initialized
+     * every time, since its initialization may be in progress or fail.
+     */
+    fn resolve_static_field_ref(
+        &mut self,
+        class: &Arc<JvmClass>,
+        index: usize,
+    ) -> Result<(Arc<MemberRef>, JvmField), usize> {
+        let (field_ref, field) = match class.get_resolved_reference(index) {
+            Some(ResolvedReference::StaticField(field_ref, field)) => {
+                (Arc::clone(field_ref), field.clone())
+            }
+            _ => {
+                let field_ref = Arc::new(read_member_ref(class, index));
+                let field = self.resolve_static_field(&field_ref, class.get_defining_loader())?;
+
+                class.set_resolved_reference(
+                    index,
+                    ResolvedReference::StaticField(Arc::clone(&field_ref), field.clone()),
+                );
+
+                (field_ref, field)
+            }
+        };
+
+        self.initialize_class(&field.get_class())?;
+
+        Ok((field_ref, field))
+    }
+
+    /**
+     * Resolves symbolic reference to a method or an interface method made by the class
+     * defined by 'loader', NoSuchMethodError is returned if there is no such method.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.3
+     */
+    fn resolve_method(
+        &mut self,
+        method_ref: &MemberRef,
+        loader: LoaderId,
+    ) -> Result<JvmMethod, usize> {
+        // array classes have no methods of their own, all of them are inherited from Object
+        let class_name = if method_ref.class_name.starts_with('[') {
+            "java/lang/Object"
+        } else {
+            &method_ref.class_name
+        };
+
+        let class = self.resolve_class(class_name, loader)?;
+
+        let resolved = if class.is_interface() {
+            class.resolve_interface_method(&method_ref.name, &method_ref.descriptor)
+        } else {
+            class.resolve_method(&method_ref.name, &method_ref.descriptor)
+        };
+
+        resolved.ok_or_else(|| {
+            self.new_exception(
+                "java/lang/NoSuchMethodError",
+                &format!(
+                    "'{}.{}{}'",
+                    method_ref.class_name, method_ref.name, method_ref.descriptor
+                ),
+            )
+        })
+    }
+
+    /**
+     * Executes method till completion and returns its result, 'None' is returned for 'void' methods.
+     * Exception which is not handled by the method is returned as an error.
+     */
+    fn invoke(
+        &mut self,
+        class: Arc<JvmClass>,
+        method_index: usize,
+        arguments: Vec<JvmValue>,
+    ) -> Result<Option<JvmValue>, usize> {
+        if class.get_method(method_index).is_abstract() {
+            return Err(self.new_abstract_method_error(&class, method_index));
+        }
+
+        if class.get_method(method_index).is_native() {
+            let native_method = find_native_method(&class, method_index);
+            return native_method(self, arguments);
+        }
+
+        let base_depth = self.frames.len();
+
+        self.push_frame(class, method_index, arguments);
+
+        loop {
+            self.executed_instructions += 1;
+
+            if self.executed_instructions >= TIME_SLICE_INSTRUCTIONS {
+                self.executed_instructions = 0;
+
+                // garbage left by objects allocated by the VM itself, like exceptions
+                if let Some(kind) = self.runtime.heap.get_due_collection(0) {
+                    self.collect_garbage(kind);
+                }
+
+                self.yield_thread();
+            }
+
+            match self.execute_instruction() {
+                ExecutionResult::Continue => {}
+
+                ExecutionResult::Return(return_value) => {
+                    self.frames.pop();
+
+                    if self.frames.len() == base_depth {
+                        return Ok(return_value);
+                    }
+
+                    // pass the result to the caller operand stack
+                    if let Some(value) = return_value {
+                        self.current_frame().push(value);
+                    }
+                }
+
+                // unhandled exception is passed to the caller of 'invoke'
+                ExecutionResult::Throw(exception) => self.unwind(exception, base_depth)?,
+            }
+        }
+    }
+
+    /**
+     * Transfers control to the exception handler, frames without suitable handler are discarded.
+     * Exception is returned back if none of the frames above 'base_depth' handles it.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.10
+     */
+    fn unwind(&mut self, exception: usize, base_depth: usize) -> Result<(), usize> {
+        let mut exception = exception;
+
+        // throwing frame points to the instruction which raised the exception
+        let mut throw_pc = self.current_frame().pc;
+
+        loop {
+            if let Some(handler_pc) = self.find_exception_handler(&mut exception, throw_pc) {
+                let frame = self.current_frame();
+
+                frame.clear_stack();
+                frame.push(JvmValue::Reference(exception));
+                frame.pc = handler_pc;
+
+                return Ok(());
+            }
+
+            // synchronized method completes abruptly, so its monitor is released
+            if let Err(monitor_exception) = self.release_method_monitor() {
+                exception = monitor_exception;
+            }
+
+            self.frames.pop();
+
+            if self.frames.len() == base_depth {
+                return Err(exception);
+            }
+
+            // caller frames point right after the invoke instruction
+            throw_pc = self.current_frame().pc - 1;
+        }
+    }
+
+    /**
+     * Finds handler of the current method protecting instruction at 'pc' and catching the exception.
+     * Exception reference is updated, since the exception may be moved while catch types are loaded.
+     */
+    fn find_exception_handler(&mut self, exception: &mut usize, pc: usize) -> Option<usize> {
+        let class = Arc::clone(&self.current_frame().class);
+        let method = class.get_method(self.current_frame().method_index);
+
+        let Some(AttributeInfo::Code {
+            exception_table, ..
+        }) = method.get_code_attribute()
+        else {
+            return None;
+        };
+
+        let mut exception_class = Arc::clone(self.runtime.heap.get(*exception).get_class());
+
+        for handler in exception_table {
+            if !handler.covers(pc) {
+                continue;
+            }
+
+            // handlers without catch type are used to implement 'finally' blocks
+            if handler.get_catch_type() == 0 {
+                return Some(handler.get_handler_pc());
+            }
+
+            let exception_handle = self.runtime.heap.create_global_handle(*exception);
+            let catch_class = self.resolve_class_ref(&class, handler.get_catch_type() as usize);
+            *exception = self.runtime.heap.delete_global_handle(exception_handle);
+
+            match catch_class {
+                Ok(catch_class) if exception_class.is_subclass_of(&catch_class) => {
+                    return Some(handler.get_handler_pc());
+                }
+                Ok(_) => {}
+                // resolution error replaces the exception, remaining handlers may catch it
+                Err(resolution_error) => {
+                    *exception = resolution_error;
+                    exception_class = Arc::clone(self.runtime.heap.get(*exception).get_class());
+                }
+            }
+        }
+
+        None
+    }
+
+    /**
+     * Initializes class by assigning constant values to static fields and running its '<clinit>'.
+     * Superclass and superinterfaces declaring default methods are initialized first.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.5
+     */
+    fn initialize_class(&mut self, class: &Arc<JvmClass>) -> Result<(), usize> {
+        loop {
+            match class.get_init_state() {
+                InitializationState::Initialized => return Ok(()),
+                // recursive request from the same thread, initialization is in progress already
+                InitializationState::BeingInitialized(thread_id) if thread_id == self.thread_id => {
+                    return Ok(())
+                }
+                // another thread initializes the class, wait till it completes
+                InitializationState::BeingInitialized(_) => self.wait_for_change(None),
+                InitializationState::Erroneous => {
+                    return Err(self.new_exception(
+                        "java/lang/NoClassDefFoundError",
+                        &format!(
+                            "Could not initialize class {}",
+                            class.get_name().replace('/', ".")
+                        ),
+                    ))
+                }
+                InitializationState::NotInitialized => break,
+            }
+        }
+
+        class.set_init_state(InitializationState::BeingInitialized(self.thread_id));
+
+        class.assign_constant_values(|text| {
+            let string = self.intern_string(text);
+
+            // interned string may be in the nursery, while the class is not
+            self.runtime
+                .heap
+                .record_static_write(class, JvmValue::Reference(string));
+
+            string
+        });
+
+        let result = self.run_initializers(class);
+
+        class.set_init_state(match result {
+            Ok(()) => InitializationState::Initialized,
+            Err(_) => InitializationState::Erroneous,
+        });
+
+        // threads waiting for initialization can continue
+        self.runtime.notify_all();
+
+        result
+    }
+
+    /**
+     * Initializes superclass and superinterfaces, then executes class initialization method.
+     */
+    fn run_initializers(&mut self, class: &Arc<JvmClass>) -> Result<(), usize> {
+        self.initialize_dependencies(class)?;
+
+        if let Some(clinit_index) = class.get_class_file().find_method("<clinit>", "()V") {
+            if let Err(exception) = self.invoke(Arc::clone(class), clinit_index, Vec::new()) {
+                return Err(self.wrap_initializer_exception(exception));
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Initializes superclass and superinterfaces which declare default methods.
+     */
+    fn initialize_dependencies(&mut self, class: &Arc<JvmClass>) -> Result<(), usize> {
+        if class.is_interface() {
+            return Ok(());
+        }
+
+        if let Some(super_class) = class.get_super_class() {
+            self.initialize_class(&Arc::clone(super_class))?;
+        }
+
+        for interface in class.get_interfaces().to_vec() {
+            if interface.declares_default_methods() {
+                self.initialize_class(&interface)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Errors thrown by '<clinit>' are propagated as is,
+     * other exceptions are wrapped into ExceptionInInitializerError.
+     */
+    fn wrap_initializer_exception(&mut self, exception: usize) -> usize {
+        let error_class = self
+            .load_class("java/lang/Error")
+            .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+
+        if self
+            .runtime
+            .heap
+            .get(exception)
+            .get_class()
+            .is_subclass_of(&error_class)
+        {
+            return exception;
+        }
+
+        self.new_throwable(
+            "java/lang/ExceptionInInitializerError",
+            "(Ljava/lang/Throwable;)V",
+            vec![JvmValue::Reference(exception)],
+        )
+    }
+
+    /**
+     * Starts method execution: native methods are completed immediately with the result pushed
+     * onto the caller operand stack, for other methods new frame is created.
+     */
+    fn start_method(
+        &mut self,
+        class: Arc<JvmClass>,
+        method_index: usize,
+        arguments: Vec<JvmValue>,
+    ) -> ExecutionResult {
+        if class.get_method(method_index).is_abstract() {
+            return ExecutionResult::Throw(self.new_abstract_method_error(&class, method_index));
+        }
+
+        if !class.get_method(method_index).is_native() {
+            self.push_frame(class, method_index, arguments);
+            return ExecutionResult::Continue;
+        }
+
+        let native_method = find_native_method(&class, method_index);
+
+        // arguments stay reachable while the native method runs, like JNI local references
+        let mut arguments = arguments;
+        let argument_handles = self.create_handles(&arguments);
+
+        if let Some(object) = self.get_method_monitor(&class, method_index, &arguments) {
+            self.enter_monitor(object);
+        }
+
+        let result = native_method(self, arguments.clone());
+
+        self.delete_handles(&mut arguments, argument_handles);
+
+        if let Some(object) = self.get_method_monitor(&class, method_index, &arguments) {
+            self.exit_monitor(object);
+        }
+
+        match result {
+            Ok(Some(value)) => self.current_frame().push(value),
+            Ok(None) => {}
+            Err(exception) => return ExecutionResult::Throw(exception),
+        }
+
+        ExecutionResult::Continue
+    }
+
+    /**
+     * Object locked by the synchronized method: 'this' for instance methods and
+     * java.lang.Class instance of the declaring class for static methods.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.11.10
+     */
+    fn get_method_monitor(
+        &mut self,
+        class: &Arc<JvmClass>,
+        method_index: usize,
+        arguments: &[JvmValue],
+    ) -> Option<usize> {
+        let method = class.get_method(method_index);
+
+        if !method.is_synchronized() {
+            return None;
+        }
+
+        if method.is_static() {
+            return Some(self.get_type_mirror(class.get_name(), class.get_defining_loader()));
+        }
+
+        match arguments[0] {
+            JvmValue::Reference(this) => Some(this),
+            other => panic!("Expected 'this' reference, but found {:?}", other),
+        }
+    }
+
+    /**
+     * Locks monitor of the object, blocks while it's owned by another thread.
+     */
+    fn enter_monitor(&mut self, object: usize) {
+        if self.runtime.heap.enter_monitor(object, self.thread_id) {
+            return;
+        }
+
+        // object stays reachable while the thread is blocked
+        let object_handle = self.runtime.heap.create_global_handle(object);
+
+        loop {
+            self.wait_for_change(None);
+
+            let object = self.runtime.heap.get_global_handle(object_handle);
+
+            if self.runtime.heap.enter_monitor(object, self.thread_id) {
+                break;
+            }
+        }
+
+        self.runtime.heap.delete_global_handle(object_handle);
+    }
+
+    /**
+     * Unlocks monitor of the object and wakes up threads blocked on it,
+     * 'false' is returned if the monitor is not owned by the current thread.
+     */
+    fn exit_monitor(&mut self, object: usize) -> bool {
+        if !self.runtime.heap.exit_monitor(object, self.thread_id) {
+            return false;
+        }
+
+        self.runtime.notify_all();
+        true
+    }
+
+    /**
+     * Releases monitor locked on entry of the current synchronized method,
+     * IllegalMonitorStateException is returned if it was already released by 'monitorexit'.
+     */
+    fn release_method_monitor(&mut self) -> Result<(), usize> {
+        let Some(object) = self.current_frame().monitor.take() else {
+            return Ok(());
+        };
+
+        if !self.exit_monitor(object) {
+            return Err(self.new_exception(
+                "java/lang/IllegalMonitorStateException",
+                "current thread is not owner",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /**
+     * Resolves symbolic reference to a static field made by the class defined by 'loader',
+     * IncompatibleClassChangeError is returned if the field is an instance one.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.getstatic
+     */
+    fn resolve_static_field(
+        &mut self,
+        field_ref: &MemberRef,
+        loader: LoaderId,
+    ) -> Result<JvmField, usize> {
+        let field = self.resolve_field(field_ref, loader)?;
+
+        let declaring_class = field.get_class();
+
+        if declaring_class
+            .get_field_offset(field.get_field_index())
+            .is_some()
+        {
+            return Err(self.new_exception(
+                "java/lang/IncompatibleClassChangeError",
+                &format!(
+                    "'{}.{}' is not static",
+                    field_ref.class_name, field_ref.name
+                ),
+            ));
+        }
+
+        Ok(field)
+    }
+
+    /**
+     * Resolves symbolic reference to an instance field made by the class defined by 'loader'
+     * and returns its slot inside the object, IncompatibleClassChangeError is returned
+     * if the field is a static one.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.getfield
+     */
+    fn resolve_instance_field(
+        &mut self,
+        field_ref: &MemberRef,
+        loader: LoaderId,
+    ) -> Result<usize, usize> {
+        let field = self.resolve_field(field_ref, loader)?;
+
+        match field.get_class().get_field_offset(field.get_field_index()) {
+            Some(offset) => Ok(offset),
+            None => Err(self.new_exception(
+                "java/lang/IncompatibleClassChangeError",
+                &format!("'{}.{}' is static", field_ref.class_name, field_ref.name),
+            )),
+        }
+    }
+
+    /**
+     * Resolves symbolic reference to a field made by the class defined by 'loader',
+     * NoSuchFieldError is returned if there is no such field.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-5.html#jvms-5.4.3.2
+     */
+    fn resolve_field(
+        &mut self,
+        field_ref: &MemberRef,
+        loader: LoaderId,
+    ) -> Result<JvmField, usize> {
+        let class = self.resolve_class(&field_ref.class_name, loader)?;
+
+        class
+            .resolve_field(&field_ref.name, &field_ref.descriptor)
+            .ok_or_else(|| {
+                self.new_exception(
+                    "java/lang/NoSuchFieldError",
+                    &format!("'{}.{}'", field_ref.class_name, field_ref.name),
+                )
+            })
+    }
+
+    /**
+     * Checks if the object can be cast to the class or array type with the given name,
+     * the type is loaded by 'loader'.
+     */
+    fn is_instance_of(
+        &mut self,
+        reference: usize,
+        class_name: &str,
+        loader: LoaderId,
+    ) -> Result<bool, usize> {
+        // class of the object is known exactly, while its name may be ambiguous
+        if !self.runtime.heap.is_array(reference) && !class_name.starts_with('[') {
+            let object_class = Arc::clone(self.runtime.heap.get(reference).get_class());
+            let target_class = self.resolve_class(class_name, loader)?;
+
+            return Ok(object_class.is_subtype_of(&target_class));
+        }
+
+        let object_type_name = self.get_object_type_name(reference);
+        self.is_assignable_to(&object_type_name, class_name, loader)
+    }
+
+    /**
+     * Checks if value of 'source' type can be assigned to 'target' type following 'checkcast'
+     * rules, array types are named by their descriptors, like '[Ljava/lang/String;'.
+     * Both types are loaded by 'loader'.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.checkcast
+     */
+    fn is_assignable_to(
+        &mut self,
+        source: &str,
+        target: &str,
+        loader: LoaderId,
+    ) -> Result<bool, usize> {
+        if source == target {
+            return Ok(true);
+        }
+
+        let assignable = match (source.strip_prefix('['), target.strip_prefix('[')) {
+            // primitive components must be the same, reference components must be assignable
+            (Some(source_component), Some(target_component)) => {
+                match (
+                    component_class_name(source_component),
+                    component_class_name(target_component),
+                ) {
+                    (Some(source_component), Some(target_component)) => {
+                        return self.is_assignable_to(source_component, target_component, loader)
+                    }
+                    _ => false,
+                }
+            }
+            // arrays implement only Cloneable and Serializable
+            (Some(_), None) => matches!(
+                target,
+                "java/lang/Object" | "java/lang/Cloneable" | "java/io/Serializable"
+            ),
+            (None, Some(_)) => false,
+            (None, None) => {
+                let source_class = self.resolve_class(source, loader)?;
+                let target_class = self.resolve_class(target, loader)?;
+
+                source_class.is_subtype_of(&target_class)
+            }
+        };
+
+        Ok(assignable)
+    }
+
+    /**
+     * Name of the object's class in internal form, arrays are named by their descriptors.
+     */
+    fn get_object_type_name(&self, reference: usize) -> String {
+        if self.runtime.heap.is_array(reference) {
+            let component_type = self.runtime.heap.get_array(reference).get_component_type();
+            format!("[{}", component_type.to_descriptor())
+        } else {
+            self.runtime
+                .heap
+                .get(reference)
+                .get_class()
+                .get_name()
+                .to_string()
+        }
+    }
+
+    /**
+     * Checks length of the new array, NegativeArraySizeException is thrown for negative lengths.
+     */
+    fn check_array_length(&mut self, count: i32) -> Result<usize, usize> {
+        if count < 0 {
+            return Err(
+                self.new_exception("java/lang/NegativeArraySizeException", &count.to_string())
+            );
+        }
+
+        Ok(count as usize)
+    }
+
+    /**
+     * Checks array index against array bounds, ArrayIndexOutOfBoundsException is thrown otherwise.
+     */
+    fn check_array_index(&mut self, array_ref: usize, index: i32) -> Result<usize, usize> {
+        let length = self.runtime.heap.get_array(array_ref).length();
+
+        if index < 0 || index as usize >= length {
+            return Err(self.new_exception(
+                "java/lang/ArrayIndexOutOfBoundsException",
+                &format!("Index {} out of bounds for length {}", index, length),
+            ));
+        }
+
+        Ok(index as usize)
+    }
+
+    /**
+     * Returns class of the object, Object class is used for arrays.
+     */
+    fn get_object_class(&mut self, reference: usize) -> Arc<JvmClass> {
+        if self.runtime.heap.is_array(reference) {
+            return self
+                .load_class("java/lang/Object")
+                .unwrap_or_else(|error| panic!("Failed to load class with error: {}", error));
+        }
+
+        Arc::clone(self.runtime.heap.get(reference).get_class())
+    }
+
+    /**
+     * Creates new frame for the method and places arguments into local variables.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.6.1
+     */
+    fn push_frame(&mut self, class: Arc<JvmClass>, method_index: usize, arguments: Vec<JvmValue>) {
+        let method = class.get_method(method_index);
+
+        let Some(AttributeInfo::Code {
+            max_stack,
+            max_locals,
+            ..
+        }) = method.get_code_attribute()
+        else {
+            panic!(
+                "No bytecode for '{}.{}' function",
+                class.get_name(),
+                method.get_name()
+            );
+        };
+
+        let descriptor = MethodDescriptor::parse(method.get_descriptor())
+            .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+        let mut frame = JvmFrame::new(Arc::clone(&class), method_index, *max_stack, *max_locals);
+
+        frame.monitor = self.get_method_monitor(&class, method_index, &arguments);
+
+        let mut arguments = arguments.into_iter();
+        let mut local_idx = 0;
+
+        if !method.is_static() {
+            frame.set_local(
+                0,
+                arguments.next().expect("'this' reference should be passed"),
+            );
+            local_idx = 1;
+        }
+
+        // long and double arguments take 2 slots
+        for (parameter, argument) in descriptor.parameters.iter().zip(arguments) {
+            frame.set_local(local_idx, argument);
+            local_idx += parameter.slots_count();
+        }
+
+        let monitor = frame.monitor;
+
+        self.frames.push(frame);
+
+        // monitor is entered after the frame is pushed, so arguments stay reachable
+        // while the thread is blocked
+        if let Some(object) = monitor {
+            self.enter_monitor(object);
+        }
+    }
+
+    fn current_frame(&mut self) -> &mut JvmFrame {
+        self.frames
+            .last_mut()
+            .expect("Call stack can't be empty here")
+    }
+
+    fn execute_instruction(&mut self) -> ExecutionResult {
+        let depth = self.frames.len();
+        let instruction_start = self.current_frame().pc;
+
+        let result = self.execute_opcode();
+
+        // exception handlers are looked up by the offset of the instruction which raised exception
+        if let ExecutionResult::Throw(_) = result {
+            self.frames[depth - 1].pc = instruction_start;
+        }
+
+        result
+    }
+
+    fn execute_opcode(&mut self) -> ExecutionResult {
+        let class = Arc::clone(&self.current_frame().class);
+        let constant_pool = class.get_constant_pool();
+
+        let method = class.get_method(self.current_frame().method_index);
+
+        let Some(AttributeInfo::Code { bytecode, .. }) = method.get_code_attribute() else {
+            panic!("Can't execute bytecode b/c AttributeInfo::Code not found");
+        };
+
+        let frame = self
+            .frames
+            .last_mut()
+            .expect("Call stack can't be empty here");
+
+        let mut pc = frame.pc;
+
+        // branch offsets are calculated relative to the opcode of the current instruction
+        let instruction_start = pc;
+
+        let opcode = Opcode::from(bytecode[pc]).expect("Can't decode bytecode instruction");
+        pc += 1;
+
+        match opcode {
+            Opcode::Nop => {}
+
+            Opcode::AconstNull => {
+                frame.push(JvmValue::Null);
+            }
+
+            Opcode::IconstM1 => {
+                frame.push(JvmValue::Int(-1));
+            }
+            Opcode::Iconst0 => {
+                frame.push(JvmValue::Int(0));
+            }
+            Opcode::Iconst1 => {
+                frame.push(JvmValue::Int(1));
+            }
+            Opcode::Iconst2 => {
+                frame.push(JvmValue::Int(2));
+            }
+            Opcode::Iconst3 => {
+                frame.push(JvmValue::Int(3));
+            }
+            Opcode::Iconst4 => {
+                frame.push(JvmValue::Int(4));
+            }
+            Opcode::Iconst5 => {
+                frame.push(JvmValue::Int(5));
+            }
+
+            Opcode::Lconst0 => {
+                frame.push(JvmValue::Long(0));
+            }
+            Opcode::Lconst1 => {
+                frame.push(JvmValue::Long(1));
+            }
+
+            Opcode::Fconst0 => {
+                frame.push(JvmValue::Float(0.0));
+            }
+            Opcode::Fconst1 => {
+                frame.push(JvmValue::Float(1.0));
+            }
+            Opcode::Fconst2 => {
+                frame.push(JvmValue::Float(2.0));
+            }
+
+            Opcode::Dconst0 => {
+                frame.push(JvmValue::Double(0.0));
+            }
+            Opcode::Dconst1 => {
+                frame.push(JvmValue::Double(1.0));
+            }
+
+            // immediate byte and short values are sign-extended to int
+            Opcode::Bipush => {
+                let byte_val = bytecode[pc] as i8;
+                pc += 1;
+
+                frame.push(JvmValue::Int(byte_val as i32));
+            }
+
+            Opcode::Sipush => {
+                let short_val = read_u16(bytecode, pc) as i16;
+                pc += 2;
+
+                frame.push(JvmValue::Int(short_val as i32));
+            }
+
+            Opcode::Ldc => {
+                let index = bytecode[pc] as usize;
+                pc += 1;
+
+                let value = match self.load_constant(&class, index) {
+                    Ok(value) => value,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                self.current_frame().push(value);
+            }
+            Opcode::LdcW => {
+                let index = read_u16(bytecode, pc) as usize;
+                pc += 2;
+
+                let value = match self.load_constant(&class, index) {
+                    Ok(value) => value,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                self.current_frame().push(value);
+            }
+
+            Opcode::Ldc2W => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let constant = constant_pool
+                    .get_constant(index as usize)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                let value = match constant {
+                    ConstantType::Long { val } => JvmValue::Long(*val as i64),
+                    ConstantType::Double { val } => JvmValue::Double(*val),
+                    other => panic!("'ldc2_w' can't load constant {:?}", other),
+                };
+
+                frame.push(value);
+            }
+
+            // long and double values take two local variable slots: 'index' and 'index + 1'
+            Opcode::Iload | Opcode::Lload | Opcode::Fload | Opcode::Dload | Opcode::Aload => {
+                let index = bytecode[pc] as u16;
+                pc += 1;
+
+                frame.load_from_local(index);
+            }
+
+            Opcode::Istore | Opcode::Lstore | Opcode::Fstore | Opcode::Dstore | Opcode::Astore => {
+                let index = bytecode[pc] as u16;
+                pc += 1;
+
+                frame.store_to_local(index);
+            }
+
+            // modifies the next load, store or iinc instruction to use u2 local variable index
+            // and 16-bit signed iinc constant
+            Opcode::Wide => {
+                let modified_opcode =
+                    Opcode::from(bytecode[pc]).expect("Can't decode bytecode instruction");
+                pc += 1;
+
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                match modified_opcode {
+                    Opcode::Iload
+                    | Opcode::Lload
+                    | Opcode::Fload
+                    | Opcode::Dload
+                    | Opcode::Aload => {
+                        frame.load_from_local(index);
+                    }
+                    Opcode::Istore
+                    | Opcode::Lstore
+                    | Opcode::Fstore
+                    | Opcode::Dstore
+                    | Opcode::Astore => {
+                        frame.store_to_local(index);
+                    }
+                    Opcode::Iinc => {
+                        let value = read_u16(bytecode, pc) as i16;
+                        pc += 2;
+
+                        increment_local(frame, index, value as i32);
+                    }
+                    other => panic!("'wide' can't modify {:?} instruction", other),
+                }
+            }
+
+            Opcode::Fload0 => {
+                frame.load_from_local(0);
+            }
+            Opcode::Fload1 => {
+                frame.load_from_local(1);
+            }
+            Opcode::Fload2 => {
+                frame.load_from_local(2);
+            }
+            Opcode::Fload3 => {
+                frame.load_from_local(3);
+            }
+
+            Opcode::Fstore0 => {
+                frame.store_to_local(0);
+            }
+            Opcode::Fstore1 => {
+                frame.store_to_local(1);
+            }
+            Opcode::Fstore2 => {
+                frame.store_to_local(2);
+            }
+            Opcode::Fstore3 => {
+                frame.store_to_local(3);
+            }
+
+            Opcode::Lload0 | Opcode::Dload0 => {
+                frame.load_from_local(0);
+            }
+            Opcode::Lload1 | Opcode::Dload1 => {
+                frame.load_from_local(1);
+            }
+            Opcode::Lload2 | Opcode::Dload2 => {
+                frame.load_from_local(2);
+            }
+            Opcode::Lload3 | Opcode::Dload3 => {
+                frame.load_from_local(3);
+            }
+
+            Opcode::Lstore0 | Opcode::Dstore0 => {
+                frame.store_to_local(0);
+            }
+            Opcode::Lstore1 | Opcode::Dstore1 => {
+                frame.store_to_local(1);
+            }
+            Opcode::Lstore2 | Opcode::Dstore2 => {
+                frame.store_to_local(2);
+            }
+            Opcode::Lstore3 | Opcode::Dstore3 => {
+                frame.store_to_local(3);
+            }
+
+            Opcode::Istore0 => {
+                frame.store_to_local(0);
+            }
+            Opcode::Istore1 => {
+                frame.store_to_local(1);
+            }
+            Opcode::Istore2 => {
+                frame.store_to_local(2);
+            }
+            Opcode::Istore3 => {
+                frame.store_to_local(3);
+            }
+
+            Opcode::Iload0 => {
+                frame.load_from_local(0);
+            }
+            Opcode::Iload1 => {
+                frame.load_from_local(1);
+            }
+            Opcode::Iload2 => {
+                frame.load_from_local(2);
+            }
+            Opcode::Iload3 => {
+                frame.load_from_local(3);
+            }
+
+            Opcode::Aload0 => {
+                frame.load_from_local(0);
+            }
+            Opcode::Aload1 => {
+                frame.load_from_local(1);
+            }
+            Opcode::Aload2 => {
+                frame.load_from_local(2);
+            }
+            Opcode::Aload3 => {
+                frame.load_from_local(3);
+            }
+
+            Opcode::Astore0 => {
+                frame.store_to_local(0);
+            }
+            Opcode::Astore1 => {
+                frame.store_to_local(1);
+            }
+            Opcode::Astore2 => {
+                frame.store_to_local(2);
+            }
+            Opcode::Astore3 => {
+                frame.store_to_local(3);
+            }
+
+            Opcode::Pop => {
+                frame.pop_words(1);
+            }
+            Opcode::Pop2 => {
+                frame.pop_words(2);
+            }
+
+            Opcode::Dup => {
+                frame.dup_words(1, 0);
+            }
+            Opcode::DupX1 => {
+                frame.dup_words(1, 1);
+            }
+            Opcode::DupX2 => {
+                frame.dup_words(1, 2);
+            }
+            Opcode::Dup2 => {
+                frame.dup_words(2, 0);
+            }
+            Opcode::Dup2X1 => {
+                frame.dup_words(2, 1);
+            }
+            Opcode::Dup2X2 => {
+                frame.dup_words(2, 2);
+            }
+
+            Opcode::Swap => {
+                frame.swap();
+            }
+
+            // integer arithmetic wraps around on overflow and shift distance is masked
+            // https://docs.oracle.com/javase/specs/jls/se17/html/jls-15.html#jls-15.19
+            Opcode::Iadd
+            | Opcode::Isub
+            | Opcode::Imul
+            | Opcode::Ishl
+            | Opcode::Ishr
+            | Opcode::Iushr
+            | Opcode::Iand
+            | Opcode::Ior
+            | Opcode::Ixor => {
+                let second_val = frame.pop_int();
+                let first_val = frame.pop_int();
+
+                let result = match opcode {
+                    Opcode::Iadd => first_val.wrapping_add(second_val),
+                    Opcode::Isub => first_val.wrapping_sub(second_val),
+                    Opcode::Imul => first_val.wrapping_mul(second_val),
+                    Opcode::Ishl => first_val.wrapping_shl(second_val as u32),
+                    Opcode::Ishr => first_val.wrapping_shr(second_val as u32),
+                    Opcode::Iushr => (first_val as u32).wrapping_shr(second_val as u32) as i32,
+                    Opcode::Iand => first_val & second_val,
+                    Opcode::Ior => first_val | second_val,
+                    _ => first_val ^ second_val,
+                };
+
+                frame.push(JvmValue::Int(result));
+            }
+
+            Opcode::Ladd
+            | Opcode::Lsub
+            | Opcode::Lmul
+            | Opcode::Land
+            | Opcode::Lor
+            | Opcode::Lxor => {
+                let second_val = frame.pop_long();
+                let first_val = frame.pop_long();
+
+                let result = match opcode {
+                    Opcode::Ladd => first_val.wrapping_add(second_val),
+                    Opcode::Lsub => first_val.wrapping_sub(second_val),
+                    Opcode::Lmul => first_val.wrapping_mul(second_val),
+                    Opcode::Land => first_val & second_val,
+                    Opcode::Lor => first_val | second_val,
+                    _ => first_val ^ second_val,
+                };
+
+                frame.push(JvmValue::Long(result));
+            }
+
+            // shift distance of long shifts is an int value
+            Opcode::Lshl | Opcode::Lshr | Opcode::Lushr => {
+                let distance = frame.pop_int() as u32;
+                let value = frame.pop_long();
+
+                let result = match opcode {
+                    Opcode::Lshl => value.wrapping_shl(distance),
+                    Opcode::Lshr => value.wrapping_shr(distance),
+                    _ => (value as u64).wrapping_shr(distance) as i64,
+                };
+
+                frame.push(JvmValue::Long(result));
+            }
+
+            // floating point arithmetic follows IEEE 754, remainder is truncating like C fmod
+            // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.8
+            Opcode::Fadd | Opcode::Fsub | Opcode::Fmul | Opcode::Fdiv | Opcode::Frem => {
+                let second_val = frame.pop_float();
+                let first_val = frame.pop_float();
+
+                let result = match opcode {
+                    Opcode::Fadd => first_val + second_val,
+                    Opcode::Fsub => first_val - second_val,
+                    Opcode::Fmul => first_val * second_val,
+                    Opcode::Fdiv => first_val / second_val,
+                    _ => first_val % second_val,
+                };
+
+                frame.push(JvmValue::Float(result));
+            }
+
+            Opcode::Dadd | Opcode::Dsub | Opcode::Dmul | Opcode::Ddiv | Opcode::Drem => {
+                let second_val = frame.pop_double();
+                let first_val = frame.pop_double();
+
+                let result = match opcode {
+                    Opcode::Dadd => first_val + second_val,
+                    Opcode::Dsub => first_val - second_val,
+                    Opcode::Dmul => first_val * second_val,
+                    Opcode::Ddiv => first_val / second_val,
+                    _ => first_val % second_val,
+                };
+
+                frame.push(JvmValue::Double(result));
+            }
+
+            Opcode::Fneg => {
+                let value = frame.pop_float();
+                frame.push(JvmValue::Float(-value));
+            }
+
+            Opcode::Dneg => {
+                let value = frame.pop_double();
+                frame.push(JvmValue::Double(-value));
+            }
+
+            // float to integer conversions round towards zero, saturate on overflow
+            // and convert NaN to zero, which is exactly what Rust 'as' casts do
+            // https://docs.oracle.com/javase/specs/jls/se17/html/jls-5.html#jls-5.1.3
+            Opcode::I2l => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Long(value as i64));
+            }
+            Opcode::I2f => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Float(value as f32));
+            }
+            Opcode::I2d => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Double(value as f64));
+            }
+            Opcode::L2i => {
+                let value = frame.pop_long();
+                frame.push(JvmValue::Int(value as i32));
+            }
+            Opcode::L2f => {
+                let value = frame.pop_long();
+                frame.push(JvmValue::Float(value as f32));
+            }
+            Opcode::L2d => {
+                let value = frame.pop_long();
+                frame.push(JvmValue::Double(value as f64));
+            }
+            Opcode::F2i => {
+                let value = frame.pop_float();
+                frame.push(JvmValue::Int(value as i32));
+            }
+            Opcode::F2l => {
+                let value = frame.pop_float();
+                frame.push(JvmValue::Long(value as i64));
+            }
+            Opcode::F2d => {
+                let value = frame.pop_float();
+                frame.push(JvmValue::Double(value as f64));
+            }
+            Opcode::D2i => {
+                let value = frame.pop_double();
+                frame.push(JvmValue::Int(value as i32));
+            }
+            Opcode::D2l => {
+                let value = frame.pop_double();
+                frame.push(JvmValue::Long(value as i64));
+            }
+            Opcode::D2f => {
+                let value = frame.pop_double();
+                frame.push(JvmValue::Float(value as f32));
+            }
+            Opcode::I2b => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Int(value as i8 as i32));
+            }
+            Opcode::I2c => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Int(value as u16 as i32));
+            }
+            Opcode::I2s => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Int(value as i16 as i32));
+            }
+
+            Opcode::Ineg => {
+                let value = frame.pop_int();
+                frame.push(JvmValue::Int(value.wrapping_neg()));
+            }
+
+            Opcode::Lneg => {
+                let value = frame.pop_long();
+                frame.push(JvmValue::Long(value.wrapping_neg()));
+            }
+
+            Opcode::Idiv | Opcode::Irem => {
+                let second_val = frame.pop_int();
+                let first_val = frame.pop_int();
+
+                if second_val == 0 {
+                    return self.throw_exception("java/lang/ArithmeticException", "/ by zero");
+                }
+
+                // Integer.MIN_VALUE / -1 overflows and results in Integer.MIN_VALUE
+                let result = match opcode {
+                    Opcode::Idiv => first_val.wrapping_div(second_val),
+                    _ => first_val.wrapping_rem(second_val),
+                };
+
+                frame.push(JvmValue::Int(result));
+            }
+
+            Opcode::Ldiv | Opcode::Lrem => {
+                let second_val = frame.pop_long();
+                let first_val = frame.pop_long();
+
+                if second_val == 0 {
+                    return self.throw_exception("java/lang/ArithmeticException", "/ by zero");
+                }
+
+                // Long.MIN_VALUE / -1 overflows and results in Long.MIN_VALUE
+                let result = match opcode {
+                    Opcode::Ldiv => first_val.wrapping_div(second_val),
+                    _ => first_val.wrapping_rem(second_val),
+                };
+
+                frame.push(JvmValue::Long(result));
+            }
+
+            Opcode::Lcmp => {
+                let second_val = frame.pop_long();
+                let first_val = frame.pop_long();
+
+                frame.push(JvmValue::Int(first_val.cmp(&second_val) as i32));
+            }
+
+            // comparisons with NaN result in -1 for 'fcmpl'/'dcmpl' and 1 for 'fcmpg'/'dcmpg'
+            Opcode::Fcmpl | Opcode::Fcmpg => {
+                let second_val = frame.pop_float();
+                let first_val = frame.pop_float();
+
+                let nan_result = match opcode {
+                    Opcode::Fcmpl => -1,
+                    _ => 1,
+                };
+
+                frame.push(JvmValue::Int(compare_floating(
+                    first_val, second_val, nan_result,
+                )));
+            }
+
+            Opcode::Dcmpl | Opcode::Dcmpg => {
+                let second_val = frame.pop_double();
+                let first_val = frame.pop_double();
+
+                let nan_result = match opcode {
+                    Opcode::Dcmpl => -1,
+                    _ => 1,
+                };
+
+                frame.push(JvmValue::Int(compare_floating(
+                    first_val, second_val, nan_result,
+                )));
+            }
+
+            Opcode::Iinc => {
+                let index = bytecode[pc] as u16;
+                pc += 1;
+
+                let value = bytecode[pc] as i8;
+                pc += 1;
+
+                increment_local(frame, index, value as i32);
+            }
+
+            Opcode::Ifeq
+            | Opcode::Ifne
+            | Opcode::Iflt
+            | Opcode::Ifge
+            | Opcode::Ifgt
+            | Opcode::Ifle => {
+                let offset = read_branch_offset(bytecode, pc);
+                pc += 2;
+
+                let value = frame.pop_int();
+
+                let jump = match opcode {
+                    Opcode::Ifeq => value == 0,
+                    Opcode::Ifne => value != 0,
+                    Opcode::Iflt => value < 0,
+                    Opcode::Ifge => value >= 0,
+                    Opcode::Ifgt => value > 0,
+                    _ => value <= 0,
+                };
+
+                if jump {
+                    pc = branch_target(instruction_start, offset);
+                }
+            }
+
+            Opcode::Ificmpeq
+            | Opcode::Ificmpne
+            | Opcode::Ificmplt
+            | Opcode::Ificmpge
+            | Opcode::Ificmpgt
+            | Opcode::Ificmple => {
+                let offset = read_branch_offset(bytecode, pc);
+                pc += 2;
+
+                // second operand is on top of the operand stack
+                let second_val = frame.pop_int();
+                let first_val = frame.pop_int();
+
+                let jump = match opcode {
+                    Opcode::Ificmpeq => first_val == second_val,
+                    Opcode::Ificmpne => first_val != second_val,
+                    Opcode::Ificmplt => first_val < second_val,
+                    Opcode::Ificmpge => first_val >= second_val,
+                    Opcode::Ificmpgt => first_val > second_val,
+                    _ => first_val <= second_val,
+                };
 
-            let mut pc = 0;
+                if jump {
+                    pc = branch_target(instruction_start, offset);
+                }
+            }
 
-            while pc < bytecode.len() {
-                let opcode = Opcode::from(bytecode[pc]).expect("Can't decode bytecode instruction");
-                pc += 1;
+            Opcode::Ifacmpeq | Opcode::Ifacmpne => {
+                let offset = read_branch_offset(bytecode, pc);
+                pc += 2;
 
-                match opcode {
-                    Opcode::Bipush => {
-                        let byte_val = bytecode[pc];
-                        pc += 1;
+                let second_ref = frame.pop_reference();
+                let first_ref = frame.pop_reference();
 
-                        frame.push(JvmValue::Int(byte_val as i32));
-                    }
+                let jump = match opcode {
+                    Opcode::Ifacmpeq => first_ref == second_ref,
+                    _ => first_ref != second_ref,
+                };
 
-                    Opcode::Ldc => {
-                        let name_idx = bytecode[pc];
-                        pc += 1;
-                        let _name = constant_pool.resolve_constant_pool_utf(name_idx as usize).
-                            expect(&format!("'ldc' can load constant at index {}", name_idx));
-                    }
+                if jump {
+                    pc = branch_target(instruction_start, offset);
+                }
+            }
 
-                    Opcode::Istore0 => {
-                        frame.store_to_local(0);
-                    }
-                    Opcode::Istore1 => {
-                        frame.store_to_local(1);
-                    }
-                    Opcode::Istore2 => {
-                        frame.store_to_local(2);
-                    }
-                    Opcode::Istore3 => {
-                        frame.store_to_local(3);
-                    }
+            Opcode::Ifnull | Opcode::Ifnonnull => {
+                let offset = read_branch_offset(bytecode, pc);
+                pc += 2;
 
-                    Opcode::Iload0 => {
-                        frame.load_from_local(0);
-                    }
-                    Opcode::Iload1 => {
-                        frame.load_from_local(1);
-                    }
-                    Opcode::Iload2 => {
-                        frame.load_from_local(2);
+                let reference = frame.pop_reference();
+
+                let jump = match opcode {
+                    Opcode::Ifnull => reference.is_none(),
+                    _ => reference.is_some(),
+                };
+
+                if jump {
+                    pc = branch_target(instruction_start, offset);
+                }
+            }
+
+            Opcode::Goto => {
+                let offset = read_branch_offset(bytecode, pc);
+                pc = branch_target(instruction_start, offset);
+            }
+
+            Opcode::GotoW => {
+                let offset = read_wide_branch_offset(bytecode, pc);
+                pc = branch_target(instruction_start, offset);
+            }
+
+            Opcode::Tableswitch => {
+                pc = align_switch_operands(pc);
+
+                let default_offset = read_i32(bytecode, pc);
+                let low = read_i32(bytecode, pc + 4);
+                let high = read_i32(bytecode, pc + 8);
+
+                let key = frame.pop_int();
+
+                // jump offsets for keys in range [low, high] follow 'low' and 'high' values
+                let offset = if key < low || key > high {
+                    default_offset
+                } else {
+                    read_i32(bytecode, pc + 12 + 4 * (key as i64 - low as i64) as usize)
+                };
+
+                pc = branch_target(instruction_start, offset);
+            }
+
+            Opcode::Lookupswitch => {
+                pc = align_switch_operands(pc);
+
+                let default_offset = read_i32(bytecode, pc);
+                let pairs_count = read_i32(bytecode, pc + 4) as usize;
+
+                let key = frame.pop_int();
+
+                // 'match-offset' pairs follow the pairs count
+                let offset = (0..pairs_count)
+                    .map(|pair_idx| pc + 8 + 8 * pair_idx)
+                    .find(|&pair_start| read_i32(bytecode, pair_start) == key)
+                    .map(|pair_start| read_i32(bytecode, pair_start + 4))
+                    .unwrap_or(default_offset);
+
+                pc = branch_target(instruction_start, offset);
+            }
+
+            Opcode::Ireturn
+            | Opcode::Lreturn
+            | Opcode::Freturn
+            | Opcode::Dreturn
+            | Opcode::Areturn => {
+                let value = frame.pop();
+
+                if let Err(exception) = self.release_method_monitor() {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                return ExecutionResult::Return(Some(value));
+            }
+
+            Opcode::Return => {
+                if let Err(exception) = self.release_method_monitor() {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                return ExecutionResult::Return(None);
+            }
+
+            Opcode::Invokestatic => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                // execution continues from the next instruction after the callee returns
+                frame.pc = pc;
+
+                let (method_ref, resolved) = match self.resolve_method_ref(&class, index as usize) {
+                    Ok(resolved) => resolved,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                let resolved_class = resolved.get_class();
+                if !resolved_class
+                    .get_method(resolved.get_method_index())
+                    .is_static()
+                {
+                    return self.throw_exception(
+                        "java/lang/IncompatibleClassChangeError",
+                        &format!(
+                            "'{}.{}' is not static",
+                            method_ref.class_name, method_ref.name
+                        ),
+                    );
+                }
+
+                if let Err(exception) = self.initialize_class(&resolved_class) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                // arguments are popped after initialization, so they stay reachable
+                // while '<clinit>' runs
+                let arguments = pop_arguments(self.current_frame(), &method_ref, false);
+
+                return self.start_method(resolved_class, resolved.get_method_index(), arguments);
+            }
+
+            Opcode::Invokevirtual | Opcode::Invokeinterface => {
+                let index = read_u16(bytecode, pc);
+
+                // 'invokeinterface' has additional 'count' operand followed by zero byte
+                pc += match opcode {
+                    Opcode::Invokeinterface => 4,
+                    _ => 2,
+                };
+
+                let method_ref = get_member_ref(&class, index as usize);
+
+                let mut arguments = pop_arguments(frame, &method_ref, true);
+
+                frame.pc = pc;
+
+                let JvmValue::Reference(receiver) = arguments[0] else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        &cannot_invoke_message(&method_ref),
+                    );
+                };
+
+                // arrays override Object.clone() with the public shallow copy
+                // https://docs.oracle.com/javase/specs/jls/se17/html/jls-10.html#jls-10.7
+                if method_ref.class_name.starts_with('[') && method_ref.name == "clone" {
+                    let copy = self.runtime.heap.clone_array(receiver);
+                    self.current_frame().push(JvmValue::Reference(copy));
+                    return ExecutionResult::Continue;
+                }
+
+                let resolved =
+                    match self.resolve_invoked_method_ref(&class, index as usize, &mut arguments) {
+                        Ok(resolved) => resolved,
+                        Err(exception) => return ExecutionResult::Throw(exception),
+                    };
+
+                if resolved
+                    .get_class()
+                    .get_method(resolved.get_method_index())
+                    .is_static()
+                {
+                    return self.throw_exception(
+                        "java/lang/IncompatibleClassChangeError",
+                        &format!("'{}.{}' is static", method_ref.class_name, method_ref.name),
+                    );
+                }
+
+                let JvmValue::Reference(receiver) = arguments[0] else {
+                    unreachable!("Receiver was checked for null already");
+                };
+
+                // method is selected from the actual class of the receiver object
+                let receiver_class = self.get_object_class(receiver);
+
+                let Some(selected) = receiver_class.select_method(&resolved) else {
+                    return self.throw_exception(
+                        "java/lang/AbstractMethodError",
+                        &format!(
+                            "'{}.{}{}' is not implemented by '{}'",
+                            method_ref.class_name,
+                            method_ref.name,
+                            method_ref.descriptor,
+                            receiver_class.get_name()
+                        ),
+                    );
+                };
+
+                return self.start_method(
+                    selected.get_class(),
+                    selected.get_method_index(),
+                    arguments,
+                );
+            }
+
+            Opcode::Invokespecial => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let method_ref = get_member_ref(&class, index as usize);
+
+                let mut arguments = pop_arguments(frame, &method_ref, true);
+
+                frame.pc = pc;
+
+                if let JvmValue::Null = arguments[0] {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        &cannot_invoke_message(&method_ref),
+                    );
+                }
+
+                let resolved =
+                    match self.resolve_invoked_method_ref(&class, index as usize, &mut arguments) {
+                        Ok(resolved) => resolved,
+                        Err(exception) => return ExecutionResult::Throw(exception),
+                    };
+
+                let resolved_class = resolved.get_class();
+                let resolved_method = resolved_class.get_method(resolved.get_method_index());
+
+                if resolved_method.is_static() {
+                    return self.throw_exception(
+                        "java/lang/IncompatibleClassChangeError",
+                        &format!("'{}.{}' is static", method_ref.class_name, method_ref.name),
+                    );
+                }
+
+                // referenced class is loaded by the same loader during method resolution already
+                let referenced_class = self
+                    .resolve_class(&method_ref.class_name, class.get_defining_loader())
+                    .expect("Referenced class should be loaded already");
+
+                // 'super.method()' calls start lookup from the direct superclass of the current class
+                let lookup_class = match class.get_super_class() {
+                    Some(super_class)
+                        if !resolved_method.is_initialization()
+                            && !referenced_class.is_interface()
+                            && !Arc::ptr_eq(&referenced_class, &class)
+                            && class.is_subclass_of(&referenced_class) =>
+                    {
+                        Arc::clone(super_class)
                     }
-                    Opcode::Iload3 => {
-                        frame.load_from_local(3);
+                    _ => referenced_class,
+                };
+
+                let Some(selected) =
+                    lookup_class.select_special_method(&method_ref.name, &method_ref.descriptor)
+                else {
+                    return self.throw_exception(
+                        "java/lang/AbstractMethodError",
+                        &format!(
+                            "'{}.{}{}'",
+                            method_ref.class_name, method_ref.name, method_ref.descriptor
+                        ),
+                    );
+                };
+
+                return self.start_method(
+                    selected.get_class(),
+                    selected.get_method_index(),
+                    arguments,
+                );
+            }
+
+            Opcode::New => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let instance_class = match self.resolve_class_ref(&class, index as usize) {
+                    Ok(instance_class) => instance_class,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                if instance_class.is_interface() || instance_class.get_class_file().is_abstract() {
+                    return self.throw_exception(
+                        "java/lang/InstantiationError",
+                        &instance_class.get_name().replace('/', "."),
+                    );
+                }
+
+                if let Err(exception) = self.initialize_class(&instance_class) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                let size = JvmHeap::entry_size(instance_class.get_instance_fields().len());
+
+                if let Err(exception) = self.reserve_heap(size) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                let reference = self.runtime.heap.allocate(instance_class);
+
+                self.current_frame().push(JvmValue::Reference(reference));
+            }
+
+            Opcode::Getfield => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let (field_ref, offset) =
+                    match self.resolve_instance_field_ref(&class, index as usize) {
+                        Ok(resolved) => resolved,
+                        Err(exception) => return ExecutionResult::Throw(exception),
+                    };
+
+                let frame = self.current_frame();
+
+                let Some(object_ref) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        &format!("Cannot read field \"{}\"", field_ref.name),
+                    );
+                };
+
+                let value = self.runtime.heap.get(object_ref).get_field(offset);
+
+                self.current_frame().push(value);
+            }
+
+            Opcode::Putfield => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let (field_ref, offset) =
+                    match self.resolve_instance_field_ref(&class, index as usize) {
+                        Ok(resolved) => resolved,
+                        Err(exception) => return ExecutionResult::Throw(exception),
+                    };
+
+                let frame = self.current_frame();
+
+                let value = frame.pop();
+                let Some(object_ref) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        &format!("Cannot assign field \"{}\"", field_ref.name),
+                    );
+                };
+
+                let object = self.runtime.heap.get_mut(object_ref);
+
+                // boolean values are narrowed to 0 or 1
+                let value = match (&object.get_class().get_instance_fields()[offset], value) {
+                    (FieldType::Boolean, JvmValue::Int(int_val)) => JvmValue::Int(int_val & 1),
+                    _ => value,
+                };
+
+                object.set_field(offset, value);
+                self.runtime.heap.record_write(object_ref, value);
+            }
+
+            Opcode::Newarray => {
+                let atype = bytecode[pc];
+                pc += 1;
+
+                // https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.newarray.desc-120
+                let component_type = match atype {
+                    4 => FieldType::Boolean,
+                    5 => FieldType::Char,
+                    6 => FieldType::Float,
+                    7 => FieldType::Double,
+                    8 => FieldType::Byte,
+                    9 => FieldType::Short,
+                    10 => FieldType::Int,
+                    11 => FieldType::Long,
+                    _ => panic!("Invalid 'newarray' type {}", atype),
+                };
+
+                let count = frame.pop_int();
+
+                let length = match self.check_array_length(count) {
+                    Ok(length) => length,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                if let Err(exception) = self.reserve_heap(JvmHeap::entry_size(length)) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                let reference = self.runtime.heap.allocate_array(component_type, length);
+
+                self.current_frame().push(JvmValue::Reference(reference));
+            }
+
+            Opcode::Anewarray => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let class_name = constant_pool
+                    .resolve_constant_pool_utf(index as usize)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                if let Err(exception) = self.resolve_type_ref(&class, index as usize, &class_name) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                let count = self.current_frame().pop_int();
+
+                let length = match self.check_array_length(count) {
+                    Ok(length) => length,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                // component is either a class or an array type like '[I'
+                let component_type = if class_name.starts_with('[') {
+                    FieldType::parse(&class_name)
+                        .unwrap_or_else(|error| panic!("Failed with error: {}", error))
+                } else {
+                    FieldType::Object(class_name)
+                };
+
+                if let Err(exception) = self.reserve_heap(JvmHeap::entry_size(length)) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                let reference = self.runtime.heap.allocate_array(component_type, length);
+
+                self.current_frame().push(JvmValue::Reference(reference));
+            }
+
+            Opcode::Multianewarray => {
+                let index = read_u16(bytecode, pc);
+                let dimensions = bytecode[pc + 2] as usize;
+                pc += 3;
+
+                let class_name = constant_pool
+                    .resolve_constant_pool_utf(index as usize)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                let array_type = FieldType::parse(&class_name)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                if let Err(exception) = self.resolve_type_ref(&class, index as usize, &class_name) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                // counts are pushed starting from the outermost dimension
+                let frame = self.current_frame();
+                let mut counts = vec![0; dimensions];
+                for count in counts.iter_mut().rev() {
+                    *count = frame.pop_int();
+                }
+
+                let mut lengths = Vec::with_capacity(dimensions);
+                for count in counts {
+                    match self.check_array_length(count) {
+                        Ok(length) => lengths.push(length),
+                        Err(exception) => return ExecutionResult::Throw(exception),
                     }
+                }
+
+                if let Err(exception) = self.reserve_heap(multi_array_size(&lengths)) {
+                    return ExecutionResult::Throw(exception);
+                }
+
+                let reference = self
+                    .runtime
+                    .heap
+                    .allocate_multi_array(&array_type, &lengths);
+
+                self.current_frame().push(JvmValue::Reference(reference));
+            }
+
+            Opcode::Arraylength => {
+                let Some(array_ref) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        "Cannot read the array length",
+                    );
+                };
+
+                let length = self.runtime.heap.get_array(array_ref).length();
+
+                self.current_frame().push(JvmValue::Int(length as i32));
+            }
+
+            Opcode::Iaload
+            | Opcode::Laload
+            | Opcode::Faload
+            | Opcode::Daload
+            | Opcode::Aaload
+            | Opcode::Baload
+            | Opcode::Caload
+            | Opcode::Saload => {
+                let index = frame.pop_int();
+
+                let Some(array_ref) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        "Cannot load from null array",
+                    );
+                };
+
+                let element_index = match self.check_array_index(array_ref, index) {
+                    Ok(element_index) => element_index,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                let value = self
+                    .runtime
+                    .heap
+                    .get_array(array_ref)
+                    .get_element(element_index);
+
+                self.current_frame().push(value);
+            }
+
+            Opcode::Iastore
+            | Opcode::Lastore
+            | Opcode::Fastore
+            | Opcode::Dastore
+            | Opcode::Aastore
+            | Opcode::Bastore
+            | Opcode::Castore
+            | Opcode::Sastore => {
+                let mut value = frame.pop();
+                let index = frame.pop_int();
+
+                let Some(mut array_ref) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        "Cannot store to null array",
+                    );
+                };
 
-                    Opcode::Iadd => {
-                        if let JvmValue::Int(first_val) = frame.pop() {
-                            if let JvmValue::Int(second_val) = frame.pop() {
-                                frame.push(JvmValue::Int(first_val + second_val));
-                            } else {
-                                panic!("Expected JvmValue::Int");
-                            }
-                        } else {
-                            panic!("Expected JvmValue::Int");
+                let element_index = match self.check_array_index(array_ref, index) {
+                    Ok(element_index) => element_index,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                // arrays are covariant, so the stored reference must be checked at runtime
+                if let JvmValue::Reference(reference) = value {
+                    let component_name =
+                        match self.runtime.heap.get_array(array_ref).get_component_type() {
+                            FieldType::Object(class_name) => class_name.clone(),
+                            other => other.to_descriptor(),
+                        };
+
+                    // component class may be loaded by Java code, which can move both objects
+                    let mut values = [JvmValue::Reference(array_ref), value];
+                    let handles = self.create_handles(&values);
+                    let is_instance = self.is_instance_of(
+                        reference,
+                        &component_name,
+                        class.get_defining_loader(),
+                    );
+                    self.delete_handles(&mut values, handles);
+
+                    let [JvmValue::Reference(moved_array_ref), JvmValue::Reference(moved_reference)] =
+                        values
+                    else {
+                        unreachable!("References kept by handles can't become null");
+                    };
+
+                    array_ref = moved_array_ref;
+                    value = JvmValue::Reference(moved_reference);
+
+                    match is_instance {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            let object_class_name = self.get_object_type_name(moved_reference);
+
+                            return self.throw_exception(
+                                "java/lang/ArrayStoreException",
+                                &object_class_name.replace('/', "."),
+                            );
                         }
+                        Err(exception) => return ExecutionResult::Throw(exception),
                     }
+                }
 
-                    Opcode::Iinc => {
-                        let _index = bytecode[pc];
-                        pc += 1;
+                self.runtime
+                    .heap
+                    .get_array_mut(array_ref)
+                    .set_element(element_index, value);
+                self.runtime.heap.record_write(array_ref, value);
+            }
 
-                        let _value = bytecode[pc] as i8;
-                        pc += 1;
-                    }
+            Opcode::Getstatic => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
 
-                    Opcode::Ificmpeq | Opcode::Ificmpne | Opcode::Ificmplt |
-                    Opcode::Ificmpge | Opcode::Ificmpgt | Opcode::Ificmple | Opcode::Goto => {
-                        let _branch1 = bytecode[pc];
-                        pc += 1;
+                let (_, field) = match self.resolve_static_field_ref(&class, index as usize) {
+                    Ok(resolved) => resolved,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
 
-                        let _branch2 = bytecode[pc];
-                        pc += 1;
-                    }
+                let value = field.get_class().get_static_value(field.get_field_index());
 
-                    Opcode::Return => {
-                        if let JvmValue::Int(val) = frame.get_local(3) {
-                            println!("res = {val}");
-                        } else {
-                            println!("Can't find result");
-                        }
+                self.current_frame().push(value);
+            }
 
-                        println!("Returning from function '{}'", method.get_name());
-                    }
+            Opcode::Putstatic => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
 
-                    Opcode::Getstatic | Opcode::Invokevirtual | Opcode::New |
-                    Opcode::Invokespecial | Opcode::Invokestatic => {
-                        let index = ((bytecode[pc] as usize) << 8) | (bytecode[pc + 1] as usize);
-                        pc += 2;
+                let (field_ref, field) = match self.resolve_static_field_ref(&class, index as usize)
+                {
+                    Ok(resolved) => resolved,
+                    Err(exception) => return ExecutionResult::Throw(exception),
+                };
+
+                let value = self.current_frame().pop();
+
+                // boolean values are narrowed to 0 or 1
+                let value = match (field_ref.descriptor.as_str(), value) {
+                    ("Z", JvmValue::Int(int_val)) => JvmValue::Int(int_val & 1),
+                    _ => value,
+                };
+
+                let field_class = field.get_class();
+                field_class.set_static_value(field.get_field_index(), value);
+                self.runtime.heap.record_static_write(&field_class, value);
+            }
+
+            Opcode::Athrow => {
+                let Some(exception) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        "Cannot throw exception",
+                    );
+                };
+
+                return ExecutionResult::Throw(exception);
+            }
+
+            Opcode::Monitorenter => {
+                let Some(object) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        "Cannot enter synchronized block",
+                    );
+                };
+
+                self.enter_monitor(object);
+            }
+
+            Opcode::Monitorexit => {
+                let Some(object) = frame.pop_reference() else {
+                    return self.throw_exception(
+                        "java/lang/NullPointerException",
+                        "Cannot exit synchronized block",
+                    );
+                };
+
+                if !self.exit_monitor(object) {
+                    return self.throw_exception(
+                        "java/lang/IllegalMonitorStateException",
+                        "current thread is not owner",
+                    );
+                }
+            }
+
+            Opcode::Instanceof => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let class_name = constant_pool
+                    .resolve_constant_pool_utf(index as usize)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                if let Err(exception) = self.resolve_type_ref(&class, index as usize, &class_name) {
+                    return ExecutionResult::Throw(exception);
+                }
 
-                        let _name = constant_pool.resolve_constant_pool_utf(index);
+                // 'null' is not an instance of any type
+                let is_instance = match self.current_frame().pop_reference() {
+                    Some(reference) => {
+                        match self.is_instance_of(
+                            reference,
+                            &class_name,
+                            class.get_defining_loader(),
+                        ) {
+                            Ok(is_instance) => is_instance,
+                            Err(exception) => return ExecutionResult::Throw(exception),
+                        }
                     }
+                    None => false,
+                };
+
+                self.current_frame().push(JvmValue::Int(is_instance as i32));
+            }
+
+            Opcode::Checkcast => {
+                let index = read_u16(bytecode, pc);
+                pc += 2;
+
+                let class_name = constant_pool
+                    .resolve_constant_pool_utf(index as usize)
+                    .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+                if let Err(exception) = self.resolve_type_ref(&class, index as usize, &class_name) {
+                    return ExecutionResult::Throw(exception);
+                }
 
-                    _ => println!("Unprocessed opcode: {:#?}", opcode),
+                // operand stack is left unchanged, 'null' can be cast to any type
+                let frame = self.current_frame();
+                let value = frame.pop();
+                frame.push(value);
+
+                if let JvmValue::Reference(reference) = value {
+                    let is_instance = match self.is_instance_of(
+                        reference,
+                        &class_name,
+                        class.get_defining_loader(),
+                    ) {
+                        Ok(is_instance) => is_instance,
+                        Err(exception) => return ExecutionResult::Throw(exception),
+                    };
+
+                    if !is_instance {
+                        let object_class_name = self.get_object_type_name(reference);
+
+                        return self.throw_exception(
+                            "java/lang/ClassCastException",
+                            &format!(
+                                "class {} cannot be cast to class {}",
+                                object_class_name.replace('/', "."),
+                                class_name.replace('/', ".")
+                            ),
+                        );
+                    }
                 }
             }
-        } else {
-            panic!("Can't execute bytecode b/c AttributeInfo::Code not found");
         }
+
+        self.current_frame().pc = pc;
+
+        ExecutionResult::Continue
+    }
+}
+
+/**
+ * Compares floating point values, 'nan_result' is returned if any of them is NaN.
+ */
+fn compare_floating<T: PartialOrd>(first_val: T, second_val: T, nan_result: i32) -> i32 {
+    match first_val.partial_cmp(&second_val) {
+        Some(ordering) => ordering as i32,
+        None => nan_result,
+    }
+}
+
+/**
+ * Slot of the instance field which VM accesses directly, like 'String.value'.
+ */
+fn get_instance_field_offset(class: &Arc<JvmClass>, name: &str, descriptor: &str) -> usize {
+    class
+        .resolve_field(name, descriptor)
+        .and_then(|field| field.get_class().get_field_offset(field.get_field_index()))
+        .unwrap_or_else(|| panic!("NoSuchFieldError: '{}.{}'", class.get_name(), name))
+}
+
+/**
+ * Detail message of NullPointerException thrown when method is invoked on 'null'.
+ */
+fn cannot_invoke_message(method_ref: &MemberRef) -> String {
+    format!(
+        "Cannot invoke \"{}.{}{}\"",
+        method_ref.class_name.replace('/', "."),
+        method_ref.name,
+        method_ref.descriptor
+    )
+}
+
+/**
+ * Finds Rust implementation for the method declared with ACC_NATIVE flag.
+ */
+fn find_native_method(class: &JvmClass, method_index: usize) -> NativeMethod {
+    let method = class.get_method(method_index);
+
+    jvm_natives::find_native_method(class.get_name(), method.get_name(), method.get_descriptor())
+        .unwrap_or_else(|| {
+            panic!(
+                "UnsatisfiedLinkError: '{}.{}{}'",
+                class.get_name(),
+                method.get_name(),
+                method.get_descriptor()
+            )
+        })
+}
+
+/**
+ * Reads CONSTANT_Fieldref, CONSTANT_Methodref or CONSTANT_InterfaceMethodref entry
+ * of the class constant pool.
+ */
+fn read_member_ref(class: &JvmClass, index: usize) -> MemberRef {
+    class
+        .get_constant_pool()
+        .resolve_member_ref(index)
+        .unwrap_or_else(|error| panic!("Failed with error: {}", error))
+}
+
+/**
+ * Member reference of the constant pool entry, it's taken from the resolution cache
+ * if the entry is resolved already.
+ */
+fn get_member_ref(class: &JvmClass, index: usize) -> Arc<MemberRef> {
+    match class.get_resolved_reference(index) {
+        Some(ResolvedReference::Method(member_ref, _))
+        | Some(ResolvedReference::InstanceField(member_ref, _))
+        | Some(ResolvedReference::StaticField(member_ref, _)) => Arc::clone(member_ref),
+        _ => Arc::new(read_member_ref(class, index)),
+    }
+}
+
+/**
+ * Pops method arguments together with the receiver object, if 'with_receiver' is set.
+ * Arguments are returned in the order of declaration.
+ */
+fn pop_arguments(
+    frame: &mut JvmFrame,
+    method_ref: &MemberRef,
+    with_receiver: bool,
+) -> Vec<JvmValue> {
+    let descriptor = MethodDescriptor::parse(&method_ref.descriptor)
+        .unwrap_or_else(|error| panic!("Failed with error: {}", error));
+
+    let arguments_count = descriptor.parameters.len() + usize::from(with_receiver);
+
+    // arguments are on the operand stack in reversed order
+    let mut arguments: Vec<JvmValue> = (0..arguments_count).map(|_| frame.pop()).collect();
+    arguments.reverse();
+
+    arguments
+}
+
+/**
+ * Size of the array allocated by 'multianewarray' together with all nested arrays.
+ */
+fn multi_array_size(lengths: &[usize]) -> usize {
+    let mut size = 0;
+    let mut arrays_count: usize = 1;
+
+    for &length in lengths {
+        size = JvmHeap::entry_size(length)
+            .saturating_mul(arrays_count)
+            .saturating_add(size);
+
+        arrays_count = arrays_count.saturating_mul(length);
+    }
+
+    size
+}
+
+/**
+ * Class name of the array component given by its descriptor, 'None' for primitive components.
+ */
+fn component_class_name(descriptor: &str) -> Option<&str> {
+    if descriptor.starts_with('[') {
+        return Some(descriptor);
+    }
+
+    descriptor
+        .strip_prefix('L')
+        .and_then(|class_name| class_name.strip_suffix(';'))
+}
+
+/**
+ * Adds constant to the int local variable with Java wrapping semantic, used by 'iinc'.
+ */
+fn increment_local(frame: &mut JvmFrame, index: u16, value: i32) {
+    if let JvmValue::Int(local_val) = frame.get_local(index) {
+        frame.set_local(index, JvmValue::Int(local_val.wrapping_add(value)));
     } else {
-        panic!("No bytecode for 'main' function, really strange");
+        panic!("Expected JvmValue::Int");
+    }
+}
+
+/**
+ * Reads unsigned 16-bit operand, like constant pool index.
+ */
+fn read_u16(bytecode: &[u8], pc: usize) -> u16 {
+    u16::from_be_bytes([bytecode[pc], bytecode[pc + 1]])
+}
+
+/**
+ * Reads signed 16-bit branch offset stored as (branchbyte1 << 8) | branchbyte2.
+ */
+fn read_branch_offset(bytecode: &[u8], pc: usize) -> i32 {
+    i16::from_be_bytes([bytecode[pc], bytecode[pc + 1]]) as i32
+}
+
+/**
+ * Reads signed 32-bit branch offset used by 'goto_w'.
+ */
+fn read_wide_branch_offset(bytecode: &[u8], pc: usize) -> i32 {
+    read_i32(bytecode, pc)
+}
+
+fn read_i32(bytecode: &[u8], pc: usize) -> i32 {
+    i32::from_be_bytes([
+        bytecode[pc],
+        bytecode[pc + 1],
+        bytecode[pc + 2],
+        bytecode[pc + 3],
+    ])
+}
+
+/**
+ * Operands of 'tableswitch' and 'lookupswitch' are preceded by 0-3 bytes of padding,
+ * so that they start at an offset which is a multiple of 4 from the start of the method code.
+ */
+fn align_switch_operands(pc: usize) -> usize {
+    pc.next_multiple_of(4)
+}
+
+fn branch_target(instruction_start: usize, offset: i32) -> usize {
+    let target = instruction_start as i64 + offset as i64;
+
+    if target < 0 {
+        panic!("Branch target {} is outside of method bytecode", target);
     }
+
+    target as usize
 }
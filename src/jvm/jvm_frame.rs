@@ -1,18 +1,59 @@
+use std::sync::Arc;
+
+use crate::jvm::jvm_class::JvmClass;
 use crate::jvm::jvm_type::JvmValue;
 
 pub struct JvmFrame {
+    // class that declares the executed method, used to access its constant pool
+    pub class: Arc<JvmClass>,
+
+    // index of the executed method inside class methods
+    pub method_index: usize,
+
+    // offset of the next instruction to execute
+    pub pc: usize,
+
     // the operand stack
     stack: Vec<JvmValue>,
 
     // the local variables slots
     local_slots: Vec<JvmValue>,
+
+    // object locked on entry of synchronized method, it's released when the method completes
+    pub monitor: Option<usize>,
 }
 
 impl JvmFrame {
-    pub fn new(stack_length: u16, locals_length: u16) -> Self {
+    pub fn new(
+        class: Arc<JvmClass>,
+        method_index: usize,
+        stack_length: u16,
+        locals_length: u16,
+    ) -> Self {
         Self {
+            class,
+            method_index,
+            pc: 0,
             stack: Vec::with_capacity(stack_length as usize),
             local_slots: vec![JvmValue::Undefined; locals_length as usize],
+            monitor: None,
+        }
+    }
+
+    /**
+     * Passes every reference held by the operand stack, local variables and the method monitor
+     * to 'visitor', they are garbage collection roots. Visitor may replace the reference
+     * if the referenced object was moved.
+     */
+    pub fn for_each_reference(&mut self, mut visitor: impl FnMut(&mut usize)) {
+        for value in self.stack.iter_mut().chain(&mut self.local_slots) {
+            if let JvmValue::Reference(reference) = value {
+                visitor(reference);
+            }
+        }
+
+        if let Some(monitor) = &mut self.monitor {
+            visitor(monitor);
         }
     }
 
@@ -20,12 +61,104 @@ impl JvmFrame {
         self.stack.push(value);
     }
 
+    /**
+     * Operand stack is discarded when exception handler is found.
+     */
+    pub fn clear_stack(&mut self) {
+        self.stack.clear();
+    }
+
     pub fn pop(&mut self) -> JvmValue {
         self.stack.pop().expect("Can't be empty here")
     }
 
+    /**
+     * Pops values occupying exactly 'words' words of the operand stack, long and double values
+     * take 2 words. Values are returned in the operand stack order.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.11.1
+     */
+    pub fn pop_words(&mut self, words: u16) -> Vec<JvmValue> {
+        let mut values = Vec::new();
+        let mut popped_words = 0;
+
+        while popped_words < words {
+            let value = self.pop();
+            popped_words += value.slots_count();
+            values.push(value);
+        }
+
+        if popped_words != words {
+            panic!("Can't split long or double value on the operand stack");
+        }
+
+        values.reverse();
+        values
+    }
+
+    /**
+     * Duplicates 'top_words' words on top of the operand stack and inserts the copy below
+     * the next 'under_words' words. All forms of 'dup', 'dup_x1', 'dup_x2', 'dup2', 'dup2_x1'
+     * and 'dup2_x2' are selected by the categories of values found on the operand stack.
+     */
+    pub fn dup_words(&mut self, top_words: u16, under_words: u16) {
+        let top = self.pop_words(top_words);
+        let under = self.pop_words(under_words);
+
+        self.stack.extend_from_slice(&top);
+        self.stack.extend(under);
+        self.stack.extend(top);
+    }
+
+    pub fn swap(&mut self) {
+        let first = self.pop_words(1);
+        let second = self.pop_words(1);
+
+        self.stack.extend(first);
+        self.stack.extend(second);
+    }
+
+    pub fn pop_int(&mut self) -> i32 {
+        match self.pop() {
+            JvmValue::Int(value) => value,
+            other => panic!("Expected JvmValue::Int, but found {:?}", other),
+        }
+    }
+
+    pub fn pop_long(&mut self) -> i64 {
+        match self.pop() {
+            JvmValue::Long(value) => value,
+            other => panic!("Expected JvmValue::Long, but found {:?}", other),
+        }
+    }
+
+    pub fn pop_float(&mut self) -> f32 {
+        match self.pop() {
+            JvmValue::Float(value) => value,
+            other => panic!("Expected JvmValue::Float, but found {:?}", other),
+        }
+    }
+
+    pub fn pop_double(&mut self) -> f64 {
+        match self.pop() {
+            JvmValue::Double(value) => value,
+            other => panic!("Expected JvmValue::Double, but found {:?}", other),
+        }
+    }
+
+    /**
+     * Pops reference from the operand stack, 'None' is returned for the 'null' value.
+     */
+    pub fn pop_reference(&mut self) -> Option<usize> {
+        match self.pop() {
+            JvmValue::Reference(address) => Some(address),
+            JvmValue::Null => None,
+            other => panic!("Expected JvmValue::Reference, but found {:?}", other),
+        }
+    }
+
     pub fn store_to_local(&mut self, local_idx: u16) {
-        self.local_slots[local_idx as usize] = self.stack.pop().expect("Can't be empty here");
+        let value = self.pop();
+        self.set_local(local_idx, value);
     }
 
     pub fn load_from_local(&mut self, local_idx: u16) {
@@ -35,4 +168,23 @@ impl JvmFrame {
     pub fn get_local(&mut self, local_idx: u16) -> JvmValue {
         self.local_slots[local_idx as usize]
     }
-}
\ No newline at end of file
+
+    /**
+     * Stores value into the local variable, long and double values take slots
+     * 'local_idx' and 'local_idx + 1'. Overwritten halves of long and double values become unusable.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.6.1
+     */
+    pub fn set_local(&mut self, local_idx: u16, value: JvmValue) {
+        let local_idx = local_idx as usize;
+
+        if local_idx > 0 && self.local_slots[local_idx - 1].slots_count() == 2 {
+            self.local_slots[local_idx - 1] = JvmValue::Undefined;
+        }
+
+        if value.slots_count() == 2 {
+            self.local_slots[local_idx + 1] = JvmValue::Undefined;
+        }
+
+        self.local_slots[local_idx] = value;
+    }
+}
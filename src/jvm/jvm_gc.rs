@@ -0,0 +1,103 @@
+use std::fmt;
+use std::time::Duration;
+
+/**
+ * Garbage collection algorithm used by the heap.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GcMode {
+    // non-moving collector, reclaimed slots are reused by the following allocations
+    MarkSweep,
+
+    // new objects are allocated in the semispace nursery, objects surviving several young
+    // collections are promoted to the old generation, which is collected by mark-compact
+    Generational,
+}
+
+/**
+ * Heap settings selected by the command line options.
+ */
+#[derive(Debug, Copy, Clone)]
+pub struct GcOptions {
+    pub mode: GcMode,
+
+    // heap can't grow beyond this size, OutOfMemoryError is thrown instead
+    pub max_heap_size: usize,
+
+    // size of a single nursery semispace, used by generational collector only
+    pub nursery_size: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CollectionKind {
+    // only nursery objects are collected, old generation is not traced
+    Young,
+
+    // all objects are collected
+    Full,
+}
+
+/**
+ * Statistics of the collections performed so far.
+ */
+#[derive(Debug, Default)]
+pub struct GcStats {
+    young_collections: usize,
+    full_collections: usize,
+
+    total_pause: Duration,
+    max_pause: Duration,
+
+    // bytes copied from the nursery into the old generation
+    promoted_bytes: usize,
+
+    // bytes taken by the unreachable objects
+    reclaimed_bytes: usize,
+}
+
+impl GcStats {
+    pub fn record_collection(&mut self, kind: CollectionKind, pause: Duration) {
+        match kind {
+            CollectionKind::Young => self.young_collections += 1,
+            CollectionKind::Full => self.full_collections += 1,
+        }
+
+        self.total_pause += pause;
+        self.max_pause = self.max_pause.max(pause);
+    }
+
+    pub fn record_promotion(&mut self, size: usize) {
+        self.promoted_bytes += size;
+    }
+
+    pub fn record_reclamation(&mut self, size: usize) {
+        self.reclaimed_bytes += size;
+    }
+}
+
+impl fmt::Display for GcStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let collections = self.young_collections + self.full_collections;
+
+        let average_pause = if collections > 0 {
+            self.total_pause / collections as u32
+        } else {
+            Duration::ZERO
+        };
+
+        writeln!(
+            f,
+            "GC collections: {} young, {} full",
+            self.young_collections, self.full_collections
+        )?;
+        writeln!(
+            f,
+            "GC pauses: total {:.3}ms, average {:.3}ms, max {:.3}ms",
+            self.total_pause.as_secs_f64() * 1000.0,
+            average_pause.as_secs_f64() * 1000.0,
+            self.max_pause.as_secs_f64() * 1000.0
+        )?;
+        writeln!(f, "GC promoted: {} bytes", self.promoted_bytes)?;
+        write!(f, "GC reclaimed: {} bytes", self.reclaimed_bytes)
+    }
+}
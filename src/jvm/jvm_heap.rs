@@ -0,0 +1,986 @@
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::class_loader::descriptor::FieldType;
+use crate::jvm::jvm_class::JvmClass;
+use crate::jvm::jvm_gc::{CollectionKind, GcMode, GcOptions, GcStats};
+use crate::jvm::jvm_type::JvmValue;
+
+/**
+ * Storage for all objects and arrays created by the running program,
+ * JvmValue::Reference is an index of the object inside the heap.
+ *
+ * With mark-and-sweep collector all objects live in the old generation and slots of reclaimed
+ * objects are reused by the following allocations. With generational collector new objects
+ * are allocated in the nursery, references of nursery objects start at SEMISPACE_STARTS,
+ * so they are distinguished from the old generation slots.
+ */
+pub struct JvmHeap {
+    mode: GcMode,
+
+    // old generation, 'None' for the slots of reclaimed objects
+    objects: Vec<Option<HeapEntry>>,
+
+    // indexes of the reclaimed old generation slots
+    free_slots: Vec<usize>,
+
+    // approximate number of bytes taken by the old generation objects
+    old_size: usize,
+
+    // nursery semispaces, objects are allocated in the active one and survivors of
+    // young collection are copied into another one
+    semispaces: [Vec<YoungEntry>; 2],
+    active_semispace: usize,
+
+    // approximate number of bytes taken by the objects of the active semispace
+    young_size: usize,
+
+    // young collection is due when the active semispace grows beyond this size
+    nursery_size: usize,
+
+    // heap can't grow beyond this size, OutOfMemoryError is thrown instead
+    max_size: usize,
+
+    // old generation size which triggers the next full collection
+    collection_threshold: usize,
+
+    // old generation objects and classes with static fields which may refer to nursery objects,
+    // they are recorded by the write barrier and serve as roots of young collection
+    // classes are keyed by address, classes with the same name may have different loaders
+    remembered_objects: HashSet<usize>,
+    remembered_classes: HashMap<usize, Arc<JvmClass>>,
+
+    // marks of reachable objects set by full collection, for the old generation slots
+    // and the active semispace
+    old_marks: Vec<bool>,
+    young_marks: Vec<bool>,
+
+    // new references of the objects moved by compaction, indexed the same way as marks
+    old_forwarding: Vec<usize>,
+    young_forwarding: Vec<usize>,
+
+    // objects promoted by the ongoing young collection, their fields are not scanned yet
+    promoted: Vec<usize>,
+
+    stats: GcStats,
+
+    // references held by the VM itself outside of Java frames, like JNI global references,
+    // keyed by handle
+    global_handles: HashMap<usize, usize>,
+    next_handle: usize,
+
+    // monitors currently held, keyed by reference of the locked object
+    monitors: HashMap<usize, JvmMonitor>,
+
+    // ids of threads waiting in Object.wait() till they are notified, keyed by object reference
+    wait_sets: HashMap<usize, Vec<i64>>,
+}
+
+enum HeapEntry {
+    Object(JvmObject),
+    Array(JvmArray),
+}
+
+enum YoungEntry {
+    // 'age' is the number of young collections survived by the object
+    Live { entry: HeapEntry, age: u8 },
+
+    // object was moved by the ongoing young collection
+    Forwarded(usize),
+}
+
+/**
+ * Place of the referenced object: old generation slot or semispace with index inside it.
+ */
+enum Location {
+    Old(usize),
+    Young(usize, usize),
+}
+
+struct JvmMonitor {
+    // id of the thread holding the monitor
+    owner: i64,
+
+    // number of times the owner entered the monitor
+    entry_count: usize,
+}
+
+pub struct JvmObject {
+    class: Arc<JvmClass>,
+
+    // instance fields values laid out according to JvmClass::get_instance_fields
+    fields: Vec<JvmValue>,
+}
+
+/**
+ * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.4
+ */
+pub struct JvmArray {
+    component_type: FieldType,
+    elements: Vec<JvmValue>,
+}
+
+// old generation size which triggers the first full collection
+const INITIAL_COLLECTION_THRESHOLD: usize = 4 * 1024 * 1024;
+
+// first references of the objects of each nursery semispace, smaller references
+// are old generation slots
+const SEMISPACE_STARTS: [usize; 2] = [1 << (usize::BITS - 2), 1 << (usize::BITS - 1)];
+
+// objects surviving this number of young collections are promoted to the old generation
+const TENURING_AGE: u8 = 2;
+
+// marks dead objects in forwarding tables
+const NOT_FORWARDED: usize = usize::MAX;
+
+impl JvmHeap {
+    pub fn new(options: GcOptions) -> Self {
+        Self {
+            mode: options.mode,
+            objects: Vec::new(),
+            free_slots: Vec::new(),
+            old_size: 0,
+            semispaces: [Vec::new(), Vec::new()],
+            active_semispace: 0,
+            young_size: 0,
+            nursery_size: options.nursery_size,
+            max_size: options.max_heap_size,
+            collection_threshold: INITIAL_COLLECTION_THRESHOLD.min(options.max_heap_size),
+            remembered_objects: HashSet::new(),
+            remembered_classes: HashMap::new(),
+            old_marks: Vec::new(),
+            young_marks: Vec::new(),
+            old_forwarding: Vec::new(),
+            young_forwarding: Vec::new(),
+            promoted: Vec::new(),
+            stats: GcStats::default(),
+            global_handles: HashMap::new(),
+            next_handle: 0,
+            monitors: HashMap::new(),
+            wait_sets: HashMap::new(),
+        }
+    }
+
+    pub fn get_mode(&self) -> GcMode {
+        self.mode
+    }
+
+    pub fn get_stats(&self) -> &GcStats {
+        &self.stats
+    }
+
+    pub fn record_pause(&mut self, kind: CollectionKind, pause: Duration) {
+        self.stats.record_collection(kind, pause);
+    }
+
+    /**
+     * Approximate number of bytes taken by object or array with 'values_count' fields or components.
+     */
+    pub fn entry_size(values_count: usize) -> usize {
+        values_count
+            .saturating_mul(mem::size_of::<JvmValue>())
+            .saturating_add(mem::size_of::<Option<HeapEntry>>())
+    }
+
+    /**
+     * Returns collection which should precede allocation of 'size' bytes, if any.
+     */
+    pub fn get_due_collection(&self, size: usize) -> Option<CollectionKind> {
+        let (old_size, young_size) = if self.is_allocated_in_nursery(size) {
+            (self.old_size, self.young_size.saturating_add(size))
+        } else {
+            (self.old_size.saturating_add(size), self.young_size)
+        };
+
+        if old_size > self.collection_threshold {
+            Some(CollectionKind::Full)
+        } else if young_size > self.nursery_size {
+            Some(CollectionKind::Young)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Checks if 'size' bytes can be allocated without exceeding the heap limit.
+     */
+    pub fn has_room_for(&self, size: usize) -> bool {
+        self.old_size
+            .saturating_add(self.young_size)
+            .saturating_add(size)
+            <= self.max_size
+    }
+
+    /**
+     * Objects too large for the nursery are allocated in the old generation directly.
+     */
+    fn is_allocated_in_nursery(&self, size: usize) -> bool {
+        self.mode == GcMode::Generational && size <= self.nursery_size / 2
+    }
+
+    fn store(&mut self, entry: HeapEntry) -> usize {
+        let size = entry.size();
+
+        if !self.is_allocated_in_nursery(size) {
+            let reference = self.store_old(entry);
+
+            // copy of a large array may refer to nursery objects
+            if self.refers_to_nursery(reference) {
+                self.remembered_objects.insert(reference);
+            }
+
+            return reference;
+        }
+
+        let semispace = &mut self.semispaces[self.active_semispace];
+        semispace.push(YoungEntry::Live { entry, age: 0 });
+
+        self.young_size += size;
+        SEMISPACE_STARTS[self.active_semispace] + semispace.len() - 1
+    }
+
+    fn store_old(&mut self, entry: HeapEntry) -> usize {
+        self.old_size += entry.size();
+
+        match self.free_slots.pop() {
+            Some(slot) => {
+                self.objects[slot] = Some(entry);
+                slot
+            }
+            None => {
+                self.objects.push(Some(entry));
+                self.objects.len() - 1
+            }
+        }
+    }
+
+    /**
+     * Allocates new instance with all fields set to default values.
+     */
+    pub fn allocate(&mut self, class: Arc<JvmClass>) -> usize {
+        let fields = class
+            .get_instance_fields()
+            .iter()
+            .map(JvmValue::default_for)
+            .collect();
+
+        self.store(HeapEntry::Object(JvmObject { class, fields }))
+    }
+
+    /**
+     * Allocates new array with all components set to default values.
+     */
+    pub fn allocate_array(&mut self, component_type: FieldType, length: usize) -> usize {
+        let elements = vec![JvmValue::default_for(&component_type); length];
+
+        self.store(HeapEntry::Array(JvmArray {
+            component_type,
+            elements,
+        }))
+    }
+
+    /**
+     * Allocates array of type 'array_type' together with nested arrays for every dimension
+     * from 'lengths', components of the innermost allocated arrays have default values.
+     */
+    pub fn allocate_multi_array(&mut self, array_type: &FieldType, lengths: &[usize]) -> usize {
+        let FieldType::Array(component_type) = array_type else {
+            panic!("Expected array type, but found {:?}", array_type);
+        };
+
+        let array_ref = self.allocate_array(component_type.as_ref().clone(), lengths[0]);
+
+        if lengths.len() > 1 {
+            for index in 0..lengths[0] {
+                let nested_ref = self.allocate_multi_array(component_type, &lengths[1..]);
+                let nested_value = JvmValue::Reference(nested_ref);
+
+                self.get_array_mut(array_ref)
+                    .set_element(index, nested_value);
+                self.record_write(array_ref, nested_value);
+            }
+        }
+
+        array_ref
+    }
+
+    /**
+     * Allocates shallow copy of the array.
+     */
+    pub fn clone_array(&mut self, reference: usize) -> usize {
+        let array = self.get_array(reference);
+
+        let copy = JvmArray {
+            component_type: array.component_type.clone(),
+            elements: array.elements.clone(),
+        };
+
+        self.store(HeapEntry::Array(copy))
+    }
+
+    /**
+     * Write barrier executed after the value is stored into the field or array component of
+     * the object. Old generation objects referring to the nursery are remembered, so young
+     * collection finds these references without tracing the whole old generation.
+     */
+    pub fn record_write(&mut self, object: usize, value: JvmValue) {
+        if let JvmValue::Reference(reference) = value {
+            if is_young(reference) && !is_young(object) {
+                self.remembered_objects.insert(object);
+            }
+        }
+    }
+
+    /**
+     * Write barrier executed after the value is stored into the static field of the class.
+     */
+    pub fn record_static_write(&mut self, class: &Arc<JvmClass>, value: JvmValue) {
+        if let JvmValue::Reference(reference) = value {
+            if is_young(reference) {
+                self.remembered_classes
+                    .entry(Arc::as_ptr(class) as usize)
+                    .or_insert_with(|| Arc::clone(class));
+            }
+        }
+    }
+
+    /**
+     * Locks monitor associated with the object, monitor can be entered multiple times by
+     * its owner thread. 'false' is returned if the monitor is owned by another thread.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.monitorenter
+     */
+    pub fn enter_monitor(&mut self, reference: usize, thread_id: i64) -> bool {
+        let monitor = self.monitors.entry(reference).or_insert(JvmMonitor {
+            owner: thread_id,
+            entry_count: 0,
+        });
+
+        if monitor.owner != thread_id {
+            return false;
+        }
+
+        monitor.entry_count += 1;
+        true
+    }
+
+    /**
+     * Decrements entry count of the monitor, it's released when the count reaches zero.
+     * 'false' is returned if the monitor is not owned by the thread.
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-6.html#jvms-6.5.monitorexit
+     */
+    pub fn exit_monitor(&mut self, reference: usize, thread_id: i64) -> bool {
+        let Some(monitor) = self.monitors.get_mut(&reference) else {
+            return false;
+        };
+
+        if monitor.owner != thread_id {
+            return false;
+        }
+
+        monitor.entry_count -= 1;
+
+        if monitor.entry_count == 0 {
+            self.monitors.remove(&reference);
+        }
+
+        true
+    }
+
+    pub fn owns_monitor(&self, reference: usize, thread_id: i64) -> bool {
+        self.monitors
+            .get(&reference)
+            .is_some_and(|monitor| monitor.owner == thread_id)
+    }
+
+    /**
+     * Releases monitor owned by the thread regardless of its entry count,
+     * the count is returned to restore it later with 'reenter_monitor'.
+     */
+    pub fn release_monitor(&mut self, reference: usize, thread_id: i64) -> usize {
+        match self.monitors.remove(&reference) {
+            Some(monitor) if monitor.owner == thread_id => monitor.entry_count,
+            _ => panic!(
+                "Monitor of {} is not owned by thread {}",
+                reference, thread_id
+            ),
+        }
+    }
+
+    /**
+     * Locks monitor released by 'release_monitor' with the same entry count.
+     * 'false' is returned if the monitor is owned by another thread.
+     */
+    pub fn reenter_monitor(
+        &mut self,
+        reference: usize,
+        thread_id: i64,
+        entry_count: usize,
+    ) -> bool {
+        if self.monitors.contains_key(&reference) {
+            return false;
+        }
+
+        self.monitors.insert(
+            reference,
+            JvmMonitor {
+                owner: thread_id,
+                entry_count,
+            },
+        );
+        true
+    }
+
+    /**
+     * Adds thread to the wait set of the object.
+     * https://docs.oracle.com/javase/specs/jls/se17/html/jls-17.html#jls-17.2
+     */
+    pub fn add_waiter(&mut self, reference: usize, thread_id: i64) {
+        self.wait_sets.entry(reference).or_default().push(thread_id);
+    }
+
+    pub fn is_waiter(&self, reference: usize, thread_id: i64) -> bool {
+        self.wait_sets
+            .get(&reference)
+            .is_some_and(|waiters| waiters.contains(&thread_id))
+    }
+
+    /**
+     * Removes thread from the wait set, used when waiting times out or is interrupted.
+     */
+    pub fn remove_waiter(&mut self, reference: usize, thread_id: i64) {
+        if let Some(waiters) = self.wait_sets.get_mut(&reference) {
+            waiters.retain(|&waiter| waiter != thread_id);
+
+            if waiters.is_empty() {
+                self.wait_sets.remove(&reference);
+            }
+        }
+    }
+
+    /**
+     * Removes the longest waiting thread from the wait set, or all of them for notifyAll.
+     */
+    pub fn notify_waiters(&mut self, reference: usize, all: bool) {
+        let Some(waiters) = self.wait_sets.get_mut(&reference) else {
+            return;
+        };
+
+        if all || waiters.len() == 1 {
+            self.wait_sets.remove(&reference);
+        } else {
+            waiters.remove(0);
+        }
+    }
+
+    /**
+     * Registers reference held by the VM code as a garbage collection root,
+     * returned handle is used to read the reference back.
+     */
+    pub fn create_global_handle(&mut self, reference: usize) -> usize {
+        let handle = self.next_handle;
+        self.next_handle += 1;
+
+        self.global_handles.insert(handle, reference);
+        handle
+    }
+
+    pub fn get_global_handle(&self, handle: usize) -> usize {
+        *self
+            .global_handles
+            .get(&handle)
+            .unwrap_or_else(|| panic!("Global handle {} doesn't exist", handle))
+    }
+
+    /**
+     * Unregisters the handle and returns the reference it holds.
+     */
+    pub fn delete_global_handle(&mut self, handle: usize) -> usize {
+        self.global_handles
+            .remove(&handle)
+            .unwrap_or_else(|| panic!("Global handle {} doesn't exist", handle))
+    }
+
+    /**
+     * Starts full collection, which marks all objects reachable from the roots passed to
+     * 'mark_root' and from the roots known to the heap itself: global handles, locked monitors
+     * and wait sets.
+     * https://docs.oracle.com/javase/specs/jls/se17/html/jls-12.html#jls-12.6
+     */
+    pub fn begin_full_collection(&mut self) {
+        self.old_marks = vec![false; self.objects.len()];
+        self.young_marks = vec![false; self.semispaces[self.active_semispace].len()];
+    }
+
+    pub fn mark_root(&mut self, reference: usize) {
+        self.mark_from(vec![reference]);
+    }
+
+    /**
+     * Completes marking of full collection, unmarked objects are garbage afterwards.
+     */
+    pub fn finish_marking(&mut self) {
+        let mut roots: Vec<usize> = self.global_handles.values().copied().collect();
+        roots.extend(self.monitors.keys());
+        roots.extend(self.wait_sets.keys());
+
+        self.mark_from(roots);
+    }
+
+    fn mark_from(&mut self, roots: Vec<usize>) {
+        let mut pending = roots;
+
+        while let Some(reference) = pending.pop() {
+            let mark = match locate(reference) {
+                Location::Old(slot) => &mut self.old_marks[slot],
+                Location::Young(_, index) => &mut self.young_marks[index],
+            };
+
+            if *mark {
+                continue;
+            }
+
+            *mark = true;
+
+            pending.extend(self.get_entry(reference).get_references());
+        }
+    }
+
+    /**
+     * Reclaims unmarked objects of mark-and-sweep collector, their slots are reused later.
+     */
+    pub fn sweep(&mut self) {
+        for (slot, entry) in self.objects.iter_mut().enumerate() {
+            if self.old_marks[slot] {
+                continue;
+            }
+
+            if let Some(entry) = entry.take() {
+                self.old_size -= entry.size();
+                self.stats.record_reclamation(entry.size());
+                self.free_slots.push(slot);
+            }
+        }
+
+        self.old_marks = Vec::new();
+        self.update_collection_threshold();
+    }
+
+    /**
+     * Assigns new references to the marked objects of generational collector: old generation
+     * objects slide towards the start of the old generation keeping their order,
+     * nursery objects are promoted right after them. Roots are updated with 'forward' afterwards.
+     */
+    pub fn plan_compaction(&mut self) {
+        let mut next_slot = 0;
+
+        let mut forward = |marked: &bool| {
+            if !marked {
+                return NOT_FORWARDED;
+            }
+
+            next_slot += 1;
+            next_slot - 1
+        };
+
+        self.old_forwarding = self.old_marks.iter().map(&mut forward).collect();
+        self.young_forwarding = self.young_marks.iter().map(&mut forward).collect();
+    }
+
+    /**
+     * New reference of the object moved by the ongoing compaction.
+     */
+    pub fn forward(&self, reference: usize) -> usize {
+        let forwarded = match locate(reference) {
+            Location::Old(slot) => self.old_forwarding[slot],
+            Location::Young(_, index) => self.young_forwarding[index],
+        };
+
+        if forwarded == NOT_FORWARDED {
+            panic!("Object at {} is unreachable, but referenced", reference);
+        }
+
+        forwarded
+    }
+
+    /**
+     * Moves marked objects to the planned places and updates references between them,
+     * nursery is empty afterwards.
+     */
+    pub fn compact(&mut self) {
+        self.global_handles = mem::take(&mut self.global_handles)
+            .into_iter()
+            .map(|(handle, reference)| (handle, self.forward(reference)))
+            .collect();
+        self.monitors = mem::take(&mut self.monitors)
+            .into_iter()
+            .map(|(reference, monitor)| (self.forward(reference), monitor))
+            .collect();
+        self.wait_sets = mem::take(&mut self.wait_sets)
+            .into_iter()
+            .map(|(reference, waiters)| (self.forward(reference), waiters))
+            .collect();
+
+        // reclaimed slots are not marked, so they are skipped together with garbage
+        let old_entries = mem::take(&mut self.objects)
+            .into_iter()
+            .zip(mem::take(&mut self.old_marks))
+            .filter_map(|(entry, marked)| entry.map(|entry| (entry, marked)));
+
+        let young_entries = mem::take(&mut self.semispaces[self.active_semispace])
+            .into_iter()
+            .zip(mem::take(&mut self.young_marks))
+            .map(|(young_entry, marked)| match young_entry {
+                YoungEntry::Live { entry, .. } => (entry, marked),
+                YoungEntry::Forwarded(_) => panic!("Nursery can't contain moved objects here"),
+            });
+
+        let old_count = self
+            .old_forwarding
+            .iter()
+            .filter(|&&slot| slot != NOT_FORWARDED)
+            .count();
+
+        let mut compacted = Vec::new();
+
+        for (mut entry, marked) in old_entries.chain(young_entries) {
+            if !marked {
+                self.stats.record_reclamation(entry.size());
+                continue;
+            }
+
+            // nursery objects follow the old generation ones
+            if compacted.len() >= old_count {
+                self.stats.record_promotion(entry.size());
+            }
+
+            for value in entry.get_values_mut() {
+                if let JvmValue::Reference(reference) = value {
+                    *reference = self.forward(*reference);
+                }
+            }
+
+            compacted.push(Some(entry));
+        }
+
+        self.objects = compacted;
+        self.old_size = self.objects.iter().flatten().map(HeapEntry::size).sum();
+        self.young_size = 0;
+        self.free_slots.clear();
+
+        // nothing refers to the nursery anymore
+        self.remembered_objects.clear();
+        self.remembered_classes.clear();
+
+        self.old_forwarding = Vec::new();
+        self.young_forwarding = Vec::new();
+
+        self.update_collection_threshold();
+    }
+
+    /**
+     * Heap is allowed to grow twice as large as the surviving objects till the next collection.
+     */
+    fn update_collection_threshold(&mut self) {
+        self.collection_threshold = self
+            .old_size
+            .saturating_mul(2)
+            .max(INITIAL_COLLECTION_THRESHOLD)
+            .min(self.max_size);
+    }
+
+    /**
+     * Starts young collection, which copies nursery objects reachable from the roots passed to
+     * 'evacuate', from the roots known to the heap itself and from the remembered old generation
+     * objects and static fields. Old generation is not traced.
+     */
+    pub fn begin_young_collection(&mut self) {
+        self.promoted.clear();
+    }
+
+    /**
+     * Copies nursery object into another semispace, or promotes it into the old generation
+     * if it's old enough. New reference of the object is returned, references to the old
+     * generation are returned as is.
+     */
+    pub fn evacuate(&mut self, reference: usize) -> usize {
+        let Location::Young(semispace, index) = locate(reference) else {
+            return reference;
+        };
+
+        // object was copied into another semispace by this collection already
+        if semispace != self.active_semispace {
+            return reference;
+        }
+
+        let young_entry = mem::replace(
+            &mut self.semispaces[semispace][index],
+            YoungEntry::Forwarded(NOT_FORWARDED),
+        );
+
+        let new_reference = match young_entry {
+            YoungEntry::Forwarded(new_reference) => new_reference,
+            YoungEntry::Live { entry, age } if age + 1 >= TENURING_AGE => {
+                self.stats.record_promotion(entry.size());
+
+                let promoted = self.store_old(entry);
+                self.promoted.push(promoted);
+                promoted
+            }
+            YoungEntry::Live { entry, age } => {
+                let survivor_space = 1 - semispace;
+                let survivors = &mut self.semispaces[survivor_space];
+
+                survivors.push(YoungEntry::Live {
+                    entry,
+                    age: age + 1,
+                });
+                SEMISPACE_STARTS[survivor_space] + survivors.len() - 1
+            }
+        };
+
+        self.semispaces[semispace][index] = YoungEntry::Forwarded(new_reference);
+        new_reference
+    }
+
+    /**
+     * Copies the rest of reachable nursery objects and swaps semispaces,
+     * objects left in the former active semispace are garbage.
+     */
+    pub fn finish_young_collection(&mut self) {
+        let global_handles = mem::take(&mut self.global_handles);
+        self.global_handles = global_handles
+            .into_iter()
+            .map(|(handle, reference)| (handle, self.evacuate(reference)))
+            .collect();
+
+        let monitors = mem::take(&mut self.monitors);
+        self.monitors = monitors
+            .into_iter()
+            .map(|(reference, monitor)| (self.evacuate(reference), monitor))
+            .collect();
+
+        let wait_sets = mem::take(&mut self.wait_sets);
+        self.wait_sets = wait_sets
+            .into_iter()
+            .map(|(reference, waiters)| (self.evacuate(reference), waiters))
+            .collect();
+
+        let remembered_objects = mem::take(&mut self.remembered_objects);
+        for &object in &remembered_objects {
+            self.evacuate_fields(object);
+        }
+
+        let remembered_classes = mem::take(&mut self.remembered_classes);
+        for class in remembered_classes.values() {
+            class.for_each_static_reference(|reference| *reference = self.evacuate(*reference));
+        }
+
+        // copied and promoted objects are scanned in turn till no new objects are copied
+        let survivor_space = 1 - self.active_semispace;
+        let mut scanned_survivors = 0;
+        let mut scanned_promoted = 0;
+
+        loop {
+            if scanned_survivors < self.semispaces[survivor_space].len() {
+                self.evacuate_fields(SEMISPACE_STARTS[survivor_space] + scanned_survivors);
+                scanned_survivors += 1;
+            } else if scanned_promoted < self.promoted.len() {
+                self.evacuate_fields(self.promoted[scanned_promoted]);
+                scanned_promoted += 1;
+            } else {
+                break;
+            }
+        }
+
+        for young_entry in self.semispaces[self.active_semispace].drain(..) {
+            if let YoungEntry::Live { entry, .. } = young_entry {
+                self.stats.record_reclamation(entry.size());
+            }
+        }
+
+        self.active_semispace = survivor_space;
+        self.young_size = self.semispaces[survivor_space]
+            .iter()
+            .map(|young_entry| match young_entry {
+                YoungEntry::Live { entry, .. } => entry.size(),
+                YoungEntry::Forwarded(_) => 0,
+            })
+            .sum();
+
+        // survivors stay in the nursery, so references to them have to be remembered
+        let promoted = mem::take(&mut self.promoted);
+        for object in remembered_objects.into_iter().chain(promoted) {
+            if self.refers_to_nursery(object) {
+                self.remembered_objects.insert(object);
+            }
+        }
+
+        for (address, class) in remembered_classes {
+            let mut refers_to_nursery = false;
+            class.for_each_static_reference(|reference| refers_to_nursery |= is_young(*reference));
+
+            if refers_to_nursery {
+                self.remembered_classes.insert(address, class);
+            }
+        }
+    }
+
+    fn evacuate_fields(&mut self, reference: usize) {
+        let mut values = mem::take(self.get_entry_mut(reference).get_values_mut());
+
+        for value in values.iter_mut() {
+            if let JvmValue::Reference(referenced) = value {
+                *referenced = self.evacuate(*referenced);
+            }
+        }
+
+        *self.get_entry_mut(reference).get_values_mut() = values;
+    }
+
+    fn refers_to_nursery(&self, reference: usize) -> bool {
+        self.get_entry(reference).get_references().any(is_young)
+    }
+
+    pub fn is_array(&self, reference: usize) -> bool {
+        matches!(self.get_entry(reference), HeapEntry::Array(_))
+    }
+
+    pub fn get(&self, reference: usize) -> &JvmObject {
+        match self.get_entry(reference) {
+            HeapEntry::Object(object) => object,
+            HeapEntry::Array(_) => panic!("Expected object, but found array at {}", reference),
+        }
+    }
+
+    pub fn get_mut(&mut self, reference: usize) -> &mut JvmObject {
+        match self.get_entry_mut(reference) {
+            HeapEntry::Object(object) => object,
+            HeapEntry::Array(_) => panic!("Expected object, but found array at {}", reference),
+        }
+    }
+
+    pub fn get_array(&self, reference: usize) -> &JvmArray {
+        match self.get_entry(reference) {
+            HeapEntry::Array(array) => array,
+            HeapEntry::Object(_) => panic!("Expected array, but found object at {}", reference),
+        }
+    }
+
+    pub fn get_array_mut(&mut self, reference: usize) -> &mut JvmArray {
+        match self.get_entry_mut(reference) {
+            HeapEntry::Array(array) => array,
+            HeapEntry::Object(_) => panic!("Expected array, but found object at {}", reference),
+        }
+    }
+
+    fn get_entry(&self, reference: usize) -> &HeapEntry {
+        let entry = match locate(reference) {
+            Location::Old(slot) => self.objects.get(slot).and_then(Option::as_ref),
+            Location::Young(semispace, index) => match self.semispaces[semispace].get(index) {
+                Some(YoungEntry::Live { entry, .. }) => Some(entry),
+                _ => None,
+            },
+        };
+
+        entry.unwrap_or_else(|| panic!("Object at {} was garbage collected", reference))
+    }
+
+    fn get_entry_mut(&mut self, reference: usize) -> &mut HeapEntry {
+        let entry = match locate(reference) {
+            Location::Old(slot) => self.objects.get_mut(slot).and_then(Option::as_mut),
+            Location::Young(semispace, index) => match self.semispaces[semispace].get_mut(index) {
+                Some(YoungEntry::Live { entry, .. }) => Some(entry),
+                _ => None,
+            },
+        };
+
+        entry.unwrap_or_else(|| panic!("Object at {} was garbage collected", reference))
+    }
+}
+
+fn locate(reference: usize) -> Location {
+    if reference >= SEMISPACE_STARTS[1] {
+        Location::Young(1, reference - SEMISPACE_STARTS[1])
+    } else if reference >= SEMISPACE_STARTS[0] {
+        Location::Young(0, reference - SEMISPACE_STARTS[0])
+    } else {
+        Location::Old(reference)
+    }
+}
+
+fn is_young(reference: usize) -> bool {
+    reference >= SEMISPACE_STARTS[0]
+}
+
+impl HeapEntry {
+    fn size(&self) -> usize {
+        JvmHeap::entry_size(self.get_values().len())
+    }
+
+    fn get_values(&self) -> &[JvmValue] {
+        match self {
+            HeapEntry::Object(object) => &object.fields,
+            HeapEntry::Array(array) => &array.elements,
+        }
+    }
+
+    fn get_values_mut(&mut self) -> &mut Vec<JvmValue> {
+        match self {
+            HeapEntry::Object(object) => &mut object.fields,
+            HeapEntry::Array(array) => &mut array.elements,
+        }
+    }
+
+    fn get_references(&self) -> impl Iterator<Item = usize> + '_ {
+        self.get_values().iter().filter_map(|value| match value {
+            JvmValue::Reference(reference) => Some(*reference),
+            _ => None,
+        })
+    }
+}
+
+impl JvmObject {
+    pub fn get_class(&self) -> &Arc<JvmClass> {
+        &self.class
+    }
+
+    pub fn get_field(&self, offset: usize) -> JvmValue {
+        self.fields[offset]
+    }
+
+    pub fn set_field(&mut self, offset: usize, value: JvmValue) {
+        self.fields[offset] = value;
+    }
+}
+
+impl JvmArray {
+    pub fn get_component_type(&self) -> &FieldType {
+        &self.component_type
+    }
+
+    pub fn length(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn get_element(&self, index: usize) -> JvmValue {
+        self.elements[index]
+    }
+
+    /**
+     * Stores value into the array, int values are truncated to the 'byte', 'char', 'short'
+     * or 'boolean' component type.
+     */
+    pub fn set_element(&mut self, index: usize, value: JvmValue) {
+        let value = match (&self.component_type, value) {
+            (FieldType::Byte, JvmValue::Int(int_val)) => JvmValue::Int(int_val as i8 as i32),
+            (FieldType::Char, JvmValue::Int(int_val)) => JvmValue::Int(int_val as u16 as i32),
+            (FieldType::Short, JvmValue::Int(int_val)) => JvmValue::Int(int_val as i16 as i32),
+            (FieldType::Boolean, JvmValue::Int(int_val)) => JvmValue::Int(int_val & 1),
+            _ => value,
+        };
+
+        self.elements[index] = value;
+    }
+}
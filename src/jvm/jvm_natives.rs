@@ -0,0 +1,438 @@
+use std::fmt::{Debug, LowerExp};
+use std::io::{self, Write};
+
+use crate::class_loader::class_registry::LoaderId;
+use crate::jvm::jvm_engine::JvmEngine;
+use crate::jvm::jvm_gc::CollectionKind;
+use crate::jvm::jvm_type::JvmValue;
+
+/**
+ * Implementation of the method declared with ACC_NATIVE flag, arguments include 'this' reference
+ * for instance methods. Error contains reference to the thrown exception.
+ */
+pub type NativeMethod = fn(&mut JvmEngine, Vec<JvmValue>) -> Result<Option<JvmValue>, usize>;
+
+pub fn find_native_method(
+    class_name: &str,
+    method_name: &str,
+    descriptor: &str,
+) -> Option<NativeMethod> {
+    match (class_name, method_name, descriptor) {
+        ("java/lang/Object", "getClass", "()Ljava/lang/Class;") => Some(object_get_class),
+        ("java/lang/Class", "getClassLoader", "()Ljava/lang/ClassLoader;") => {
+            Some(class_get_class_loader)
+        }
+        ("java/lang/Class", "newInstance", "()Ljava/lang/Object;") => Some(class_new_instance),
+        ("java/lang/ClassLoader", "getSystemClassLoader", "()Ljava/lang/ClassLoader;") => {
+            Some(class_loader_get_system_class_loader)
+        }
+        ("java/lang/ClassLoader", "register", "(Ljava/lang/ClassLoader;Z)I") => {
+            Some(class_loader_register)
+        }
+        ("java/lang/ClassLoader", "defineClass0", "(Ljava/lang/String;[BII)Ljava/lang/Class;") => {
+            Some(class_loader_define_class)
+        }
+        ("java/lang/ClassLoader", "findLoadedClass0", "(Ljava/lang/String;)Ljava/lang/Class;") => {
+            Some(class_loader_find_loaded_class)
+        }
+        (
+            "java/lang/ClassLoader",
+            "findBootstrapClass",
+            "(Ljava/lang/String;)Ljava/lang/Class;",
+        ) => Some(class_loader_find_bootstrap_class),
+        (
+            "java/lang/ClassLoader",
+            "findApplicationClass",
+            "(Ljava/lang/String;)Ljava/lang/Class;",
+        ) => Some(class_loader_find_application_class),
+        ("java/lang/Object", "wait", "(J)V") => Some(object_wait),
+        ("java/lang/Object", "notify", "()V") => Some(object_notify),
+        ("java/lang/Object", "notifyAll", "()V") => Some(object_notify_all),
+        ("java/lang/String", "intern", "()Ljava/lang/String;") => Some(string_intern),
+        ("java/lang/System", "gc", "()V") => Some(system_gc),
+        ("java/lang/Thread", "currentThread", "()Ljava/lang/Thread;") => {
+            Some(thread_current_thread)
+        }
+        ("java/lang/Thread", "sleep", "(J)V") => Some(thread_sleep),
+        ("java/lang/Thread", "yield", "()V") => Some(thread_yield),
+        ("java/lang/Thread", "start0", "()V") => Some(thread_start),
+        ("java/lang/Thread", "isAlive", "()Z") => Some(thread_is_alive),
+        ("java/lang/Thread", "join0", "(J)V") => Some(thread_join),
+        ("java/lang/Thread", "interrupt0", "()V") => Some(thread_interrupt),
+        ("java/io/PrintStream", "writeBoolean", "(IZ)V") => Some(print_stream_write_boolean),
+        ("java/io/PrintStream", "writeChar", "(IC)V") => Some(print_stream_write_char),
+        ("java/io/PrintStream", "writeInt", "(II)V") => Some(print_stream_write_int),
+        ("java/io/PrintStream", "writeLong", "(IJ)V") => Some(print_stream_write_long),
+        ("java/io/PrintStream", "writeFloat", "(IF)V") => Some(print_stream_write_float),
+        ("java/io/PrintStream", "writeDouble", "(ID)V") => Some(print_stream_write_double),
+        ("java/io/PrintStream", "writeString", "(ILjava/lang/String;)V") => {
+            Some(print_stream_write_string)
+        }
+        _ => None,
+    }
+}
+
+fn object_get_class(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let mirror = engine.get_object_class_mirror(reference_argument(&arguments, 0))?;
+    Ok(Some(JvmValue::Reference(mirror)))
+}
+
+fn class_get_class_loader(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let loader = engine.get_class_loader(reference_argument(&arguments, 0))?;
+    Ok(Some(loader))
+}
+
+fn class_new_instance(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let object = engine.new_instance(reference_argument(&arguments, 0))?;
+    Ok(Some(JvmValue::Reference(object)))
+}
+
+fn class_loader_get_system_class_loader(
+    engine: &mut JvmEngine,
+    _arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let loader = engine.get_system_class_loader()?;
+    Ok(Some(JvmValue::Reference(loader)))
+}
+
+fn class_loader_register(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let system = int_argument(&arguments, 1) != 0;
+    let id = engine.register_class_loader(reference_argument(&arguments, 0), system);
+    Ok(Some(JvmValue::Int(id)))
+}
+
+fn class_loader_define_class(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    // name is optional, bounds of the array region are checked by java.lang.ClassLoader
+    let name = match arguments[1] {
+        JvmValue::Reference(reference) => Some(engine.get_string(reference)),
+        _ => None,
+    };
+
+    let data = engine.get_byte_array_region(
+        reference_argument(&arguments, 2),
+        int_argument(&arguments, 3) as usize,
+        int_argument(&arguments, 4) as usize,
+    );
+
+    let mirror = engine.define_class(reference_argument(&arguments, 0), name.as_deref(), data)?;
+    Ok(Some(JvmValue::Reference(mirror)))
+}
+
+fn class_loader_find_loaded_class(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let name = engine.get_string(reference_argument(&arguments, 1));
+    let mirror = engine.find_loaded_class(reference_argument(&arguments, 0), &name);
+    Ok(Some(mirror.map_or(JvmValue::Null, JvmValue::Reference)))
+}
+
+fn class_loader_find_bootstrap_class(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let name = engine.get_string(reference_argument(&arguments, 0));
+    let mirror = engine.find_builtin_class(&name, LoaderId::Bootstrap)?;
+    Ok(Some(mirror.map_or(JvmValue::Null, JvmValue::Reference)))
+}
+
+fn class_loader_find_application_class(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let name = engine.get_string(reference_argument(&arguments, 0));
+    let mirror = engine.find_builtin_class(&name, LoaderId::Application)?;
+    Ok(Some(mirror.map_or(JvmValue::Null, JvmValue::Reference)))
+}
+
+fn object_wait(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let millis = long_argument(&arguments, 1);
+
+    if millis < 0 {
+        return Err(engine.new_exception(
+            "java/lang/IllegalArgumentException",
+            "timeout value is negative",
+        ));
+    }
+
+    engine.wait_on_object(reference_argument(&arguments, 0), millis)?;
+    Ok(None)
+}
+
+fn object_notify(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    engine.notify_object(reference_argument(&arguments, 0), false)?;
+    Ok(None)
+}
+
+fn object_notify_all(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    engine.notify_object(reference_argument(&arguments, 0), true)?;
+    Ok(None)
+}
+
+fn string_intern(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let text = engine.get_string(reference_argument(&arguments, 0));
+    Ok(Some(JvmValue::Reference(engine.intern_string(&text))))
+}
+
+fn system_gc(engine: &mut JvmEngine, _arguments: Vec<JvmValue>) -> Result<Option<JvmValue>, usize> {
+    engine.collect_garbage(CollectionKind::Full);
+    Ok(None)
+}
+
+fn thread_current_thread(
+    engine: &mut JvmEngine,
+    _arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    // 'main' thread doesn't exist while its own java.lang.Thread instance is created
+    let thread = match engine.current_thread() {
+        Some(thread_object) => JvmValue::Reference(thread_object),
+        None => JvmValue::Null,
+    };
+    Ok(Some(thread))
+}
+
+fn thread_sleep(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let millis = long_argument(&arguments, 0);
+
+    if millis < 0 {
+        return Err(engine.new_exception(
+            "java/lang/IllegalArgumentException",
+            "timeout value is negative",
+        ));
+    }
+
+    engine.sleep(millis)?;
+    Ok(None)
+}
+
+fn thread_yield(
+    engine: &mut JvmEngine,
+    _arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    engine.yield_thread();
+    Ok(None)
+}
+
+fn thread_start(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    engine.start_thread(reference_argument(&arguments, 0));
+    Ok(None)
+}
+
+fn thread_is_alive(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let alive = engine.is_thread_alive(reference_argument(&arguments, 0));
+    Ok(Some(JvmValue::Int(alive as i32)))
+}
+
+fn thread_join(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    engine.join_thread(
+        reference_argument(&arguments, 0),
+        long_argument(&arguments, 1),
+    )?;
+    Ok(None)
+}
+
+fn thread_interrupt(
+    engine: &mut JvmEngine,
+    _arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    engine.interrupt_thread();
+    Ok(None)
+}
+
+fn print_stream_write_boolean(
+    _engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let value = int_argument(&arguments, 1) != 0;
+    write_to_fd(int_argument(&arguments, 0), &value.to_string());
+    Ok(None)
+}
+
+fn print_stream_write_char(
+    _engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let value = char::from_u32(int_argument(&arguments, 1) as u16 as u32)
+        .unwrap_or(char::REPLACEMENT_CHARACTER);
+    write_to_fd(int_argument(&arguments, 0), &value.to_string());
+    Ok(None)
+}
+
+fn print_stream_write_int(
+    _engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let value = int_argument(&arguments, 1);
+    write_to_fd(int_argument(&arguments, 0), &value.to_string());
+    Ok(None)
+}
+
+fn print_stream_write_long(
+    _engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let JvmValue::Long(value) = arguments[1] else {
+        panic!(
+            "Expected JvmValue::Long argument, but found {:?}",
+            arguments[1]
+        );
+    };
+    write_to_fd(int_argument(&arguments, 0), &value.to_string());
+    Ok(None)
+}
+
+fn print_stream_write_float(
+    _engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let JvmValue::Float(value) = arguments[1] else {
+        panic!(
+            "Expected JvmValue::Float argument, but found {:?}",
+            arguments[1]
+        );
+    };
+    write_to_fd(int_argument(&arguments, 0), &format_floating(value));
+    Ok(None)
+}
+
+fn print_stream_write_double(
+    _engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let JvmValue::Double(value) = arguments[1] else {
+        panic!(
+            "Expected JvmValue::Double argument, but found {:?}",
+            arguments[1]
+        );
+    };
+    write_to_fd(int_argument(&arguments, 0), &format_floating(value));
+    Ok(None)
+}
+
+fn print_stream_write_string(
+    engine: &mut JvmEngine,
+    arguments: Vec<JvmValue>,
+) -> Result<Option<JvmValue>, usize> {
+    let value = match arguments[1] {
+        JvmValue::Reference(reference) => engine.get_string(reference),
+        _ => "null".to_string(),
+    };
+    write_to_fd(int_argument(&arguments, 0), &value);
+    Ok(None)
+}
+
+fn int_argument(arguments: &[JvmValue], index: usize) -> i32 {
+    match arguments[index] {
+        JvmValue::Int(value) => value,
+        other => panic!("Expected JvmValue::Int argument, but found {:?}", other),
+    }
+}
+
+fn long_argument(arguments: &[JvmValue], index: usize) -> i64 {
+    match arguments[index] {
+        JvmValue::Long(value) => value,
+        other => panic!("Expected JvmValue::Long argument, but found {:?}", other),
+    }
+}
+
+fn reference_argument(arguments: &[JvmValue], index: usize) -> usize {
+    match arguments[index] {
+        JvmValue::Reference(value) => value,
+        other => panic!(
+            "Expected JvmValue::Reference argument, but found {:?}",
+            other
+        ),
+    }
+}
+
+/**
+ * Formats value the same way as Float.toString and Double.toString do: plain notation
+ * for magnitudes in range [10^-3, 10^7) and computerized scientific notation, like '1.0E10',
+ * otherwise. Like Java 19+, Rust prints the shortest digits sequence which identifies the value.
+ */
+fn format_floating<T: Copy + Debug + LowerExp + Into<f64>>(value: T) -> String {
+    let wide_value: f64 = value.into();
+
+    if wide_value.is_nan() {
+        return "NaN".to_string();
+    }
+
+    if wide_value.is_infinite() {
+        return if wide_value > 0.0 {
+            "Infinity"
+        } else {
+            "-Infinity"
+        }
+        .to_string();
+    }
+
+    let magnitude = wide_value.abs();
+
+    if magnitude == 0.0 || (1e-3..1e7).contains(&magnitude) {
+        // debug representation always keeps fractional part, like '1.0'
+        return format!("{:?}", value);
+    }
+
+    let scientific = format!("{:e}", value);
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("Scientific notation should contain exponent");
+
+    if mantissa.contains('.') {
+        format!("{}E{}", mantissa, exponent)
+    } else {
+        format!("{}.0E{}", mantissa, exponent)
+    }
+}
+
+/**
+ * Writes text into the standard error for fd 2, and into the standard output otherwise.
+ */
+fn write_to_fd(fd: i32, text: &str) {
+    let result = if fd == 2 {
+        io::stderr().write_all(text.as_bytes())
+    } else {
+        io::stdout().write_all(text.as_bytes())
+    };
+
+    result.unwrap_or_else(|error| panic!("Can't write to fd {} with error: {}", fd, error));
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+/**
+ * Global VM lock. Every Java thread runs on its own OS thread, but bytecode is executed only
+ * by the thread holding the lock, so the state shared by all threads is never accessed
+ * concurrently. Lock is released periodically and while the thread is blocked.
+ *
+ * Acquiring and releasing the lock synchronizes threads, so every field access, volatile
+ * or not, is sequentially consistent and long/double values are never torn
+ * (https://docs.oracle.com/javase/specs/jls/se17/html/jls-17.html#jls-17.4).
+ */
+pub struct JvmLock<T> {
+    state: Mutex<T>,
+
+    // signalled every time a blocked thread can make progress: monitor is released,
+    // thread terminates or class initialization completes
+    changed: Condvar,
+
+    // number of threads waiting to acquire the lock
+    waiting: AtomicUsize,
+
+    // number of times the lock was acquired, used to hand the lock over to waiting threads
+    acquisitions: AtomicU64,
+}
+
+impl<T> JvmLock<T> {
+    /**
+     * Lock lives as long as the VM process, so it's shared by all threads as static reference.
+     */
+    pub fn new(state: T) -> &'static Self {
+        Box::leak(Box::new(Self {
+            state: Mutex::new(state),
+            changed: Condvar::new(),
+            waiting: AtomicUsize::new(0),
+            acquisitions: AtomicU64::new(0),
+        }))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, T> {
+        self.waiting.fetch_add(1, Ordering::SeqCst);
+
+        let guard = self.state.lock().expect("VM lock is poisoned");
+
+        self.waiting.fetch_sub(1, Ordering::SeqCst);
+        self.acquisitions.fetch_add(1, Ordering::SeqCst);
+
+        guard
+    }
+}
+
+/**
+ * Access to the shared state owned by the current thread, dereferences to the state
+ * while the lock is held.
+ */
+pub struct JvmLockGuard<T: 'static> {
+    lock: &'static JvmLock<T>,
+    guard: Option<MutexGuard<'static, T>>,
+}
+
+impl<T> JvmLockGuard<T> {
+    pub fn acquire(lock: &'static JvmLock<T>) -> Self {
+        Self {
+            lock,
+            guard: Some(lock.lock()),
+        }
+    }
+
+    pub fn get_lock(&self) -> &'static JvmLock<T> {
+        self.lock
+    }
+
+    /**
+     * Blocks till some other thread calls 'notify_all', lock is released while waiting.
+     */
+    pub fn wait(&mut self) {
+        let guard = self.guard.take().expect("VM lock is not held");
+
+        self.guard = Some(self.lock.changed.wait(guard).expect("VM lock is poisoned"));
+    }
+
+    /**
+     * Same as 'wait', but returns after 'timeout' even if nobody calls 'notify_all'.
+     */
+    pub fn wait_timeout(&mut self, timeout: Duration) {
+        let guard = self.guard.take().expect("VM lock is not held");
+
+        let (guard, _) = self
+            .lock
+            .changed
+            .wait_timeout(guard, timeout)
+            .expect("VM lock is poisoned");
+
+        self.guard = Some(guard);
+    }
+
+    pub fn notify_all(&self) {
+        self.lock.changed.notify_all();
+    }
+
+    /**
+     * Gives threads waiting for the lock a chance to run, the current thread continues
+     * as soon as any of them acquires the lock.
+     */
+    pub fn yield_lock(&mut self) {
+        if self.lock.waiting.load(Ordering::SeqCst) == 0 {
+            return;
+        }
+
+        let acquisitions = self.lock.acquisitions.load(Ordering::SeqCst);
+
+        self.guard = None;
+
+        loop {
+            let guard = self.lock.lock();
+
+            // lock was acquired by another thread in between or nobody needs it anymore
+            if self.lock.acquisitions.load(Ordering::SeqCst) > acquisitions + 1
+                || self.lock.waiting.load(Ordering::SeqCst) == 0
+            {
+                self.guard = Some(guard);
+                return;
+            }
+
+            drop(guard);
+            thread::yield_now();
+        }
+    }
+}
+
+impl<T> Deref for JvmLockGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.guard.as_ref().expect("VM lock is not held")
+    }
+}
+
+impl<T> DerefMut for JvmLockGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.guard.as_mut().expect("VM lock is not held")
+    }
+}
+
+/**
+ * Registry of Java threads which were started and not terminated yet, keyed by thread id.
+ */
+#[derive(Default)]
+pub struct JvmThreads {
+    threads: HashMap<i64, JvmThreadInfo>,
+}
+
+struct JvmThreadInfo {
+    // java.lang.Thread instance of the thread
+    thread_object: usize,
+
+    // VM exits without waiting for daemon threads
+    daemon: bool,
+}
+
+impl JvmThreads {
+    pub fn register(&mut self, thread_id: i64, thread_object: usize, daemon: bool) {
+        self.threads.insert(
+            thread_id,
+            JvmThreadInfo {
+                thread_object,
+                daemon,
+            },
+        );
+    }
+
+    pub fn remove(&mut self, thread_id: i64) {
+        self.threads.remove(&thread_id);
+    }
+
+    pub fn is_alive(&self, thread_id: i64) -> bool {
+        self.threads.contains_key(&thread_id)
+    }
+
+    pub fn get_thread_object(&self, thread_id: i64) -> Option<usize> {
+        self.threads
+            .get(&thread_id)
+            .map(|thread| thread.thread_object)
+    }
+
+    pub fn for_each_thread_object(&mut self, mut visitor: impl FnMut(&mut usize)) {
+        for thread in self.threads.values_mut() {
+            visitor(&mut thread.thread_object);
+        }
+    }
+
+    pub fn has_non_daemon_threads(&self) -> bool {
+        self.threads.values().any(|thread| !thread.daemon)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::class_loader::descriptor::FieldType;
+
 #[derive(Debug, Copy, Clone)]
 pub enum JvmValue {
     Int(i32),
@@ -7,6 +9,38 @@ pub enum JvmValue {
     Double(f64),
 
     Reference(usize),
+    Null,
 
     Undefined,
-}
\ No newline at end of file
+}
+
+impl JvmValue {
+    /**
+     * Initial value of fields and array components
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.3
+     */
+    pub fn default_for(field_type: &FieldType) -> JvmValue {
+        match field_type {
+            FieldType::Byte
+            | FieldType::Char
+            | FieldType::Int
+            | FieldType::Short
+            | FieldType::Boolean => JvmValue::Int(0),
+            FieldType::Long => JvmValue::Long(0),
+            FieldType::Float => JvmValue::Float(0.0),
+            FieldType::Double => JvmValue::Double(0.0),
+            FieldType::Object(_) | FieldType::Array(_) => JvmValue::Null,
+        }
+    }
+
+    /**
+     * long and double values are of category 2 and occupy two local variable slots
+     * https://docs.oracle.com/javase/specs/jvms/se17/html/jvms-2.html#jvms-2.11.1
+     */
+    pub fn slots_count(&self) -> u16 {
+        match self {
+            JvmValue::Long(_) | JvmValue::Double(_) => 2,
+            _ => 1,
+        }
+    }
+}
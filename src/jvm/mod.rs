@@ -1,4 +1,9 @@
+pub mod jvm_class;
 pub mod jvm_engine;
+pub mod jvm_gc;
+pub mod jvm_heap;
+pub mod jvm_natives;
+pub mod jvm_thread;
 pub mod jvm_type;
 
 
@@ -2,10 +2,14 @@
 
 use core::panic;
 use std::env;
+use std::process;
 use std::io::{Error, ErrorKind};
+use std::path::Path;
 
 use crate::class_loader::class_registry::ClassRegistry;
-use crate::jvm::jvm_engine;
+use crate::class_loader::jar_manifest::JarManifest;
+use crate::jvm::jvm_engine::JvmEngine;
+use crate::jvm::jvm_gc::{GcMode, GcOptions};
 
 mod class_loader;
 mod jvm;
@@ -20,21 +24,28 @@ fn main() {
         Ok(ctx) => {
             let main_class_name = &ctx.main_class;
 
-            let global_class_registry = ClassRegistry::new(ctx.class_path);
-
-            let maybe_main_class = global_class_registry.load_class(main_class_name);
-
-            match maybe_main_class {
-                Ok(class_file) => {
-                    match class_file.main_method() {
-                        Ok(main_method) => {
-                            println!("'main'  found and will be executed");
-                            jvm_engine::execute_bytecode(main_method, class_file.get_constant_pool());
-                        }
-                        Err(error) => panic!("Failed with error: {}", error),
-                    }
-                }
-                Err(err) => panic!("Failed to load main class with error: {}", err),
+            let global_class_registry =
+                ClassRegistry::new(&ctx.class_path, ctx.bootstrap_class_path.as_deref())
+                .unwrap_or_else(|error| panic!("Failed with {}", error));
+
+            let mut engine = JvmEngine::new(global_class_registry, ctx.gc_options);
+
+            let result = engine.execute_main(main_class_name, &ctx.program_args);
+
+            if let Err(err) = &result {
+                eprintln!("{}", err);
+            }
+
+            // VM exits when all non-daemon threads terminate
+            engine.wait_for_non_daemon_threads();
+
+            if ctx.verbose_gc {
+                eprintln!("{}", engine.get_gc_stats());
+            }
+
+            // uncaught exception terminates the program with non-zero exit code like 'java' does
+            if result.is_err() {
+                process::exit(1);
             }
 
             println!("JVM exited successfully");
@@ -43,6 +54,14 @@ fn main() {
     }
 }
 
+// maximum heap size used when '-Xmx' option is not specified
+const DEFAULT_MAX_HEAP_SIZE: usize = 256 * 1024 * 1024;
+
+// bounds of the nursery size used when '-Xmn' option is not specified,
+// by default nursery takes 1/8 of the maximum heap size
+const MIN_DEFAULT_NURSERY_SIZE: usize = 64 * 1024;
+const MAX_DEFAULT_NURSERY_SIZE: usize = 8 * 1024 * 1024;
+
 fn parse_launch_params(args: &[String]) -> Result<LaunchContex, Error> {
     if args.is_empty() {
         return Err(Error::new(
@@ -51,21 +70,85 @@ fn parse_launch_params(args: &[String]) -> Result<LaunchContex, Error> {
         ));
     }
 
-    // if single argument passed we assume that this is main class for execution
-    // example: com.max.Hello
-    if args.len() == 1 {
-        return Ok(LaunchContex {
-            class_path: ".".to_string(),
-            main_class: args[0].to_string(),
-        });
+    let mut class_path = ".".to_string();
+    let mut bootstrap_class_path = None;
+    let mut max_heap_size = DEFAULT_MAX_HEAP_SIZE;
+    let mut nursery_size = None;
+    let mut gc_mode = GcMode::MarkSweep;
+    let mut verbose_gc = false;
+    let mut jar_file = None;
+
+    // options precede the main class or '-jar' option, the rest of arguments are passed to 'main'
+    // example: -cp path/to/classes -Xmx64m -Xgc:generational com.max.Hello arg1 arg2
+    // example: -Xbootclasspath:/opt/rust-vm/rt -cp path/to/classes com.max.Hello
+    // example: -Xmx64m -jar path/to/app.jar arg1 arg2
+    let mut index = 0;
+    while index < args.len() && args[index].starts_with('-') {
+        let option = &args[index];
+
+        if option == "-cp" && index + 1 < args.len() {
+            class_path = args[index + 1].to_string();
+            index += 2;
+        } else if option == "-jar" && index + 1 < args.len() {
+            jar_file = Some(args[index + 1].to_string());
+            index += 2;
+            break;
+        } else if let Some(path) = option.strip_prefix("-Xbootclasspath:") {
+            bootstrap_class_path = Some(path.to_string());
+            index += 1;
+        } else if let Some(size) = option.strip_prefix("-Xmx") {
+            max_heap_size = parse_memory_size(size)?;
+            index += 1;
+        } else if let Some(size) = option.strip_prefix("-Xmn") {
+            nursery_size = Some(parse_memory_size(size)?);
+            index += 1;
+        } else if option == "-Xgc:mark-sweep" {
+            gc_mode = GcMode::MarkSweep;
+            index += 1;
+        } else if option == "-Xgc:generational" {
+            gc_mode = GcMode::Generational;
+            index += 1;
+        } else if option == "-verbose:gc" {
+            verbose_gc = true;
+            index += 1;
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized option '{}'", option),
+            ));
+        }
     }
 
-    // if 3 argument passed we assume that first 2 point to the class path folder
-    // example: -cp path/to/classes com.max.Hello
-    if args.len() == 3 {
+    let main_class = if let Some(jar_file) = jar_file {
+        // class path is taken from the JAR file, '-cp' option is ignored like in 'java'
+        let (jar_class_path, main_class) = read_jar_launch_params(&jar_file)?;
+        class_path = jar_class_path;
+        Some(main_class)
+    } else if index < args.len() {
+        index += 1;
+        Some(args[index - 1].to_string())
+    } else {
+        None
+    };
+
+    if let Some(main_class) = main_class {
         return Ok(LaunchContex {
-            class_path: args[1].to_string(),
-            main_class: args[2].to_string(),
+            class_path,
+            bootstrap_class_path,
+            main_class,
+            program_args: args[index..].to_vec(),
+            gc_options: GcOptions {
+                mode: gc_mode,
+                max_heap_size,
+                // nursery can't take more than half of the heap
+                nursery_size: nursery_size
+                    .unwrap_or_else(|| {
+                        (max_heap_size / 8)
+                            .clamp(MIN_DEFAULT_NURSERY_SIZE, MAX_DEFAULT_NURSERY_SIZE)
+                    })
+                    .min(max_heap_size / 2),
+            },
+            verbose_gc,
         });
     }
 
@@ -75,7 +158,68 @@ fn parse_launch_params(args: &[String]) -> Result<LaunchContex, Error> {
     ))
 }
 
+/**
+ * Class path and main class of the executable JAR file taken from its manifest,
+ * JAR file itself is the first class path entry followed by 'Class-Path' attribute entries.
+ */
+fn read_jar_launch_params(jar_file: &str) -> Result<(String, String), Error> {
+    let jar_path = env::current_dir()?.join(jar_file);
+
+    let manifest = JarManifest::read(&jar_path)?;
+
+    let main_class = manifest.get_main_class().ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("no main manifest attribute, in {}", jar_file),
+        )
+    })?;
+
+    let class_path: Vec<String> = [jar_path.as_path()]
+        .into_iter()
+        .chain(manifest.get_class_path().iter().map(Path::new))
+        .map(|path| path.display().to_string())
+        .collect();
+
+    Ok((class_path.join(":"), main_class.to_string()))
+}
+
+/**
+ * Parses memory size in bytes with optional 'k', 'm' or 'g' suffix, like '64m'.
+ */
+fn parse_memory_size(size: &str) -> Result<usize, Error> {
+    let (digits, multiplier) = match size.chars().last() {
+        Some('k' | 'K') => (&size[..size.len() - 1], 1024),
+        Some('m' | 'M') => (&size[..size.len() - 1], 1024 * 1024),
+        Some('g' | 'G') => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+        _ => (size, 1),
+    };
+
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|value| value.checked_mul(multiplier))
+        .filter(|&value| value > 0)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid memory size '{}'", size),
+            )
+        })
+}
+
 struct LaunchContex {
     class_path: String,
+
+    // directory or archive with bootstrap classes, 'java/rt' near the executable if not specified
+    bootstrap_class_path: Option<String>,
+
     main_class: String,
+
+    // arguments following the main class or JAR file, they are passed to 'main' method
+    program_args: Vec<String>,
+
+    gc_options: GcOptions,
+
+    // print garbage collection statistics on exit
+    verbose_gc: bool,
 }
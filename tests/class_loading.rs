@@ -0,0 +1,21 @@
+mod common;
+
+use common::{expected_output, run_fixture};
+
+// 'loading.Main' defines 'plugin.Greeter' from bytes with its own loader and then tries
+// to define corrupted copies of the class, each of them must fail with ClassFormatError
+#[test]
+fn defines_classes_from_bytes() {
+    assert_eq!(
+        run_fixture("loading", "loading.Main", &[]),
+        expected_output("loading", "loading.Main")
+    );
+}
+
+#[test]
+fn defines_classes_from_bytes_with_generational_gc() {
+    assert_eq!(
+        run_fixture("loading", "loading.Main", &["-Xgc:generational", "-Xmn16k"]),
+        expected_output("loading", "loading.Main")
+    );
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+use std::process::Command;
+
+/**
+ * Runs main class of the fixture from 'tests/fixtures/<fixture>/classes' and returns
+ * everything it printed to the standard output, without messages of rust-vm itself.
+ */
+pub fn run_fixture(fixture: &str, main_class: &str, vm_options: &[&str]) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let class_path = root.join("tests/fixtures").join(fixture).join("classes");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-vm"))
+        .arg(format!(
+            "-Xbootclasspath:{}",
+            root.join("java/rt").display()
+        ))
+        .args(vm_options)
+        .arg("-cp")
+        .arg(class_path)
+        .arg(main_class)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        output.status.success(),
+        "{} failed with {}\n{}{}",
+        main_class,
+        output.status,
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    stdout
+        .lines()
+        .filter(|line| !line.starts_with("Loading class '") && *line != "JVM exited successfully")
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+/**
+ * Expected output of the fixture main class, it's kept in
+ * 'tests/fixtures/<fixture>/expected/<main_class>.txt'.
+ */
+pub fn expected_output(fixture: &str, main_class: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixture)
+        .join("expected")
+        .join(format!("{}.txt", main_class));
+
+    std::fs::read_to_string(path).unwrap()
+}
@@ -0,0 +1,7 @@
+#!/usr/bin/env bash
+# Compiles interpreter fixtures, their expected output is produced by HotSpot:
+#   java -cp classes interpreter.Arithmetic > expected/interpreter.Arithmetic.txt
+cd "$(dirname "$0")" || exit 1
+
+rm -rf classes
+javac --release 17 -d classes src/interpreter/*.java
@@ -0,0 +1,50 @@
+package interpreter;
+
+public class Arithmetic {
+
+    static void ints(int a, int b) {
+        System.out.println(a + b);
+        System.out.println(a - b);
+        System.out.println(a * b);
+        System.out.println(a / b);
+        System.out.println(a % b);
+        System.out.println(-a);
+        System.out.println(a << 33);
+        System.out.println(a >> b);
+        System.out.println(a >>> b);
+        System.out.println(a & b);
+        System.out.println(a | b);
+        System.out.println(a ^ b);
+        System.out.println(a < b);
+        System.out.println(a == b);
+    }
+
+    static void divide(int a, int b) {
+        try {
+            System.out.println(a / b);
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+    }
+
+    public static void main(String[] args) {
+        ints(Integer.MAX_VALUE, 3);
+        ints(-1000, -7);
+        ints(Integer.MIN_VALUE, -1);
+        ints(12, 12);
+        divide(1, 0);
+
+        int i = 32767;
+        i += 100;
+        i -= 1;
+        System.out.println(i);
+
+        short s = -300;
+        System.out.println(s);
+        byte b = -100;
+        System.out.println(b);
+        char c = 'A';
+        c += 2;
+        System.out.println(c);
+    }
+}
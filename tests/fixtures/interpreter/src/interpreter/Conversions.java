@@ -0,0 +1,36 @@
+package interpreter;
+
+public class Conversions {
+
+    static void fromDouble(double d) {
+        System.out.println((int) d);
+        System.out.println((long) d);
+        System.out.println((float) d);
+        float f = (float) d;
+        System.out.println((int) f);
+        System.out.println((long) f);
+    }
+
+    static void fromInt(int i, long l) {
+        System.out.println((long) i);
+        System.out.println((float) i);
+        System.out.println((double) i);
+        System.out.println((int) l);
+        System.out.println((double) l);
+        System.out.println((byte) i);
+        System.out.println((int) (char) i);
+        System.out.println((short) i);
+    }
+
+    public static void main(String[] args) {
+        fromDouble(3.99);
+        fromDouble(-3.99);
+        fromDouble(1e20);
+        fromDouble(-1e20);
+        fromDouble(Double.NaN);
+        fromDouble(Double.POSITIVE_INFINITY);
+        fromInt(70000, 123456789012345L);
+        fromInt(-129, -1L);
+        fromInt(-2000000, Long.MAX_VALUE);
+    }
+}
@@ -0,0 +1,72 @@
+package interpreter;
+
+public class LongsAndDoubles {
+
+    static final long BIG = 123456789012345L;
+
+    static double ratio = 2.5;
+
+    static void longs(long a, long b) {
+        System.out.println(a + b);
+        System.out.println(a - b);
+        System.out.println(a * b);
+        System.out.println(a / b);
+        System.out.println(a % b);
+        System.out.println(-a);
+        System.out.println(a << 65);
+        System.out.println(a >> 3);
+        System.out.println(a >>> 3);
+        System.out.println(a & b);
+        System.out.println(a | b);
+        System.out.println(a ^ b);
+        System.out.println(a < b);
+        System.out.println(a == a);
+    }
+
+    static void doubles(double a, double b) {
+        System.out.println(a + b);
+        System.out.println(a - b);
+        System.out.println(a * b);
+        System.out.println(a / b);
+        System.out.println(a % b);
+        System.out.println(-a);
+        System.out.println(a < b);
+        System.out.println(a >= b);
+        System.out.println(a != b);
+    }
+
+    static void floats(float a, float b) {
+        System.out.println(a + b);
+        System.out.println(a - b);
+        System.out.println(a * b);
+        System.out.println(a / b);
+        System.out.println(a % b);
+        System.out.println(a > b);
+    }
+
+    // wide arguments take two slots, so 'c' and 'd' are read from the right slots
+    static long pick(int a, long b, double c, long d) {
+        return a == 0 ? b : d;
+    }
+
+    public static void main(String[] args) {
+        longs(Long.MAX_VALUE, 7);
+        longs(Long.MIN_VALUE, -1);
+        longs(-9876543210L, 1000L);
+        doubles(0.5, 0.25);
+        doubles(-5.5, 0.0);
+        doubles(Double.NaN, 1.0);
+        floats(1.5f, 0.25f);
+        floats(-7.25f, 2f);
+        floats(1f, 0f);
+        System.out.println(BIG);
+        System.out.println(ratio);
+        System.out.println(pick(0, BIG, ratio, -1));
+        System.out.println(pick(1, BIG, ratio, -5000000000L));
+
+        long[] longArray = {1L, Long.MIN_VALUE};
+        double[] doubleArray = {0.125, -1.0E10};
+        System.out.println(longArray[1] - longArray[0]);
+        System.out.println(doubleArray[0] * doubleArray[1]);
+    }
+}
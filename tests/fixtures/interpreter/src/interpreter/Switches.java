@@ -0,0 +1,56 @@
+package interpreter;
+
+public class Switches {
+
+    // compiled to 'tableswitch'
+    static int dense(int key) {
+        switch (key) {
+            case 1: return 10;
+            case 2: return 20;
+            case 3: return 30;
+            case 5: return 50;
+            default: return -1;
+        }
+    }
+
+    // compiled to 'lookupswitch'
+    static int sparse(int key) {
+        switch (key) {
+            case -100000: return 1;
+            case 7: return 2;
+            case 1000: return 3;
+            case 123456: return 4;
+            default: return 0;
+        }
+    }
+
+    // 'Aa' and 'BB' have the same hash code
+    static int strings(String s) {
+        switch (s) {
+            case "apple": return 1;
+            case "banana": return 2;
+            case "Aa": return 3;
+            case "BB": return 4;
+            default: return 0;
+        }
+    }
+
+    public static void main(String[] args) {
+        for (int i = -1; i < 7; i++) {
+            System.out.println(dense(i));
+        }
+
+        System.out.println(sparse(-100000));
+        System.out.println(sparse(7));
+        System.out.println(sparse(1000));
+        System.out.println(sparse(123456));
+        System.out.println(sparse(8));
+        System.out.println(sparse(Integer.MIN_VALUE));
+
+        System.out.println(strings("apple"));
+        System.out.println(strings("banana"));
+        System.out.println(strings("Aa"));
+        System.out.println(strings("BB"));
+        System.out.println(strings("cherry"));
+    }
+}
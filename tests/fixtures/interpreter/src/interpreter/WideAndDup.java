@@ -0,0 +1,78 @@
+package interpreter;
+
+public class WideAndDup {
+
+    int intField;
+
+    long longField;
+
+    double doubleField;
+
+    static long twice(long value) {
+        return value * 2;
+    }
+
+    // 130 long locals take 260 slots, so the last ones are accessed with 'wide' prefix
+    static void wideLocals() {
+        long l0 = 0, l1 = 1, l2 = 2, l3 = 3, l4 = 4, l5 = 5, l6 = 6, l7 = 7, l8 = 8, l9 = 9;
+        long l10 = 10, l11 = 11, l12 = 12, l13 = 13, l14 = 14, l15 = 15, l16 = 16, l17 = 17, l18 = 18, l19 = 19;
+        long l20 = 20, l21 = 21, l22 = 22, l23 = 23, l24 = 24, l25 = 25, l26 = 26, l27 = 27, l28 = 28, l29 = 29;
+        long l30 = 30, l31 = 31, l32 = 32, l33 = 33, l34 = 34, l35 = 35, l36 = 36, l37 = 37, l38 = 38, l39 = 39;
+        long l40 = 40, l41 = 41, l42 = 42, l43 = 43, l44 = 44, l45 = 45, l46 = 46, l47 = 47, l48 = 48, l49 = 49;
+        long l50 = 50, l51 = 51, l52 = 52, l53 = 53, l54 = 54, l55 = 55, l56 = 56, l57 = 57, l58 = 58, l59 = 59;
+        long l60 = 60, l61 = 61, l62 = 62, l63 = 63, l64 = 64, l65 = 65, l66 = 66, l67 = 67, l68 = 68, l69 = 69;
+        long l70 = 70, l71 = 71, l72 = 72, l73 = 73, l74 = 74, l75 = 75, l76 = 76, l77 = 77, l78 = 78, l79 = 79;
+        long l80 = 80, l81 = 81, l82 = 82, l83 = 83, l84 = 84, l85 = 85, l86 = 86, l87 = 87, l88 = 88, l89 = 89;
+        long l90 = 90, l91 = 91, l92 = 92, l93 = 93, l94 = 94, l95 = 95, l96 = 96, l97 = 97, l98 = 98, l99 = 99;
+        long l100 = 100, l101 = 101, l102 = 102, l103 = 103, l104 = 104, l105 = 105, l106 = 106, l107 = 107, l108 = 108, l109 = 109;
+        long l110 = 110, l111 = 111, l112 = 112, l113 = 113, l114 = 114, l115 = 115, l116 = 116, l117 = 117, l118 = 118, l119 = 119;
+        long l120 = 120, l121 = 121, l122 = 122, l123 = 123, l124 = 124, l125 = 125, l126 = 126, l127 = 127, l128 = 128, l129 = 129;
+        int counter = 5;
+        double ratio = 0.5;
+
+        counter += 1000;
+        l129 += l0 + l1;
+        ratio *= l128;
+
+        System.out.println(l129);
+        System.out.println(counter);
+        System.out.println(ratio);
+        System.out.println(l3 + l64 + l127);
+    }
+
+    static void duplicates(WideAndDup object, int[] ints, long[] longs) {
+        // dup_x1 and dup2_x1
+        int i = object.intField = 42;
+        long l = object.longField = 5000000000L;
+        System.out.println(i);
+        System.out.println(l);
+
+        // dup2_x1 of the old value
+        long old = object.longField++;
+        System.out.println(old);
+        System.out.println(object.longField);
+
+        double d = object.doubleField += 1.5;
+        System.out.println(d);
+
+        // dup_x2 and dup2_x2
+        System.out.println(ints[1] = 5);
+        System.out.println(longs[0] = 77L);
+
+        // dup2 of array and index
+        ints[0] += 3;
+        longs[1]++;
+        System.out.println(ints[0]);
+        System.out.println(longs[1]);
+
+        // pop2 of the ignored result
+        twice(3);
+        object.intField++;
+        System.out.println(object.intField);
+    }
+
+    public static void main(String[] args) {
+        wideLocals();
+        duplicates(new WideAndDup(), new int[2], new long[2]);
+    }
+}
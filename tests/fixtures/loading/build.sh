@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+# Compiles the class loading fixture, 'plugin.Greeter' is only available as bytes embedded
+# into 'loading.GreeterBytes', so it can be loaded by the custom loader only
+cd "$(dirname "$0")" || exit 1
+
+plugin_dir=$(mktemp -d)
+javac --release 17 -d "$plugin_dir" src/plugin/Greeter.java
+
+{
+    echo "package loading;"
+    echo
+    echo "// generated by build.sh from src/plugin/Greeter.java"
+    echo "class GreeterBytes {"
+    echo "    static final byte[] BYTES = {"
+    od -An -v -td1 "$plugin_dir/plugin/Greeter.class" |
+        awk '{ line = "       "; for (i = 1; i <= NF; i++) line = line " " $i ","; print line }'
+    echo "    };"
+    echo "}"
+} > src/loading/GreeterBytes.java
+
+rm -rf "$plugin_dir" classes
+javac --release 17 -d classes src/loading/*.java
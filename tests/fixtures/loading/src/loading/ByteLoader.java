@@ -0,0 +1,27 @@
+package loading;
+
+/**
+ * Loader without parent, it defines 'plugin.Greeter' from the given bytes.
+ */
+public class ByteLoader extends ClassLoader {
+
+    private final byte[] bytes;
+
+    public ByteLoader(byte[] bytes) {
+        super(null);
+        this.bytes = bytes;
+    }
+
+    @Override
+    protected Class<?> findClass(String name) throws ClassNotFoundException {
+        if (!name.equals("plugin.Greeter")) {
+            throw new ClassNotFoundException(name);
+        }
+
+        return defineClass(name, bytes, 0, bytes.length);
+    }
+
+    public Class<?> define(int length) {
+        return defineClass("plugin.Greeter", bytes, 0, length);
+    }
+}
@@ -0,0 +1,43 @@
+package loading;
+
+// generated by build.sh from src/plugin/Greeter.java
+class GreeterBytes {
+    static final byte[] BYTES = {
+        -54, -2, -70, -66, 0, 0, 0, 61, 0, 38, 10, 0, 2, 0, 3, 7,
+        0, 4, 12, 0, 5, 0, 6, 1, 0, 16, 106, 97, 118, 97, 47, 108,
+        97, 110, 103, 47, 79, 98, 106, 101, 99, 116, 1, 0, 6, 60, 105, 110,
+        105, 116, 62, 1, 0, 3, 40, 41, 86, 9, 0, 8, 0, 9, 7, 0,
+        10, 12, 0, 11, 0, 12, 1, 0, 14, 112, 108, 117, 103, 105, 110, 47,
+        71, 114, 101, 101, 116, 101, 114, 1, 0, 9, 103, 114, 101, 101, 116, 105,
+        110, 103, 115, 1, 0, 1, 73, 9, 0, 14, 0, 15, 7, 0, 16, 12,
+        0, 17, 0, 18, 1, 0, 16, 106, 97, 118, 97, 47, 108, 97, 110, 103,
+        47, 83, 121, 115, 116, 101, 109, 1, 0, 3, 111, 117, 116, 1, 0, 21,
+        76, 106, 97, 118, 97, 47, 105, 111, 47, 80, 114, 105, 110, 116, 83, 116,
+        114, 101, 97, 109, 59, 8, 0, 20, 1, 0, 24, 104, 101, 108, 108, 111,
+        32, 102, 114, 111, 109, 32, 100, 101, 102, 105, 110, 101, 100, 32, 99, 108,
+        97, 115, 115, 10, 0, 22, 0, 23, 7, 0, 24, 12, 0, 25, 0, 26,
+        1, 0, 19, 106, 97, 118, 97, 47, 105, 111, 47, 80, 114, 105, 110, 116,
+        83, 116, 114, 101, 97, 109, 1, 0, 7, 112, 114, 105, 110, 116, 108, 110,
+        1, 0, 21, 40, 76, 106, 97, 118, 97, 47, 108, 97, 110, 103, 47, 83,
+        116, 114, 105, 110, 103, 59, 41, 86, 10, 0, 22, 0, 28, 12, 0, 25,
+        0, 29, 1, 0, 4, 40, 73, 41, 86, 7, 0, 31, 1, 0, 18, 106,
+        97, 118, 97, 47, 108, 97, 110, 103, 47, 82, 117, 110, 110, 97, 98, 108,
+        101, 1, 0, 4, 67, 111, 100, 101, 1, 0, 15, 76, 105, 110, 101, 78,
+        117, 109, 98, 101, 114, 84, 97, 98, 108, 101, 1, 0, 3, 114, 117, 110,
+        1, 0, 8, 60, 99, 108, 105, 110, 105, 116, 62, 1, 0, 10, 83, 111,
+        117, 114, 99, 101, 70, 105, 108, 101, 1, 0, 12, 71, 114, 101, 101, 116,
+        101, 114, 46, 106, 97, 118, 97, 0, 33, 0, 8, 0, 2, 0, 1, 0,
+        30, 0, 1, 0, 8, 0, 11, 0, 12, 0, 0, 0, 3, 0, 1, 0,
+        5, 0, 6, 0, 1, 0, 32, 0, 0, 0, 29, 0, 1, 0, 1, 0,
+        0, 0, 5, 42, -73, 0, 1, -79, 0, 0, 0, 1, 0, 33, 0, 0,
+        0, 6, 0, 1, 0, 0, 0, 3, 0, 1, 0, 34, 0, 6, 0, 1,
+        0, 32, 0, 0, 0, 62, 0, 2, 0, 1, 0, 0, 0, 26, -78, 0,
+        7, 4, 96, -77, 0, 7, -78, 0, 13, 18, 19, -74, 0, 21, -78, 0,
+        13, -78, 0, 7, -74, 0, 27, -79, 0, 0, 0, 1, 0, 33, 0, 0,
+        0, 18, 0, 4, 0, 0, 0, 9, 0, 8, 0, 10, 0, 16, 0, 11,
+        0, 25, 0, 12, 0, 8, 0, 35, 0, 6, 0, 1, 0, 32, 0, 0,
+        0, 30, 0, 1, 0, 0, 0, 0, 0, 6, 16, 41, -77, 0, 7, -79,
+        0, 0, 0, 1, 0, 33, 0, 0, 0, 6, 0, 1, 0, 0, 0, 5,
+        0, 1, 0, 36, 0, 0, 0, 2, 0, 37,
+    };
+}
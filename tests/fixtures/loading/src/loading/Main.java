@@ -0,0 +1,59 @@
+package loading;
+
+public class Main {
+
+    public static void main(String[] args) throws Exception {
+        ByteLoader loader = new ByteLoader(GreeterBytes.BYTES);
+
+        Class<?> greeterClass = loader.loadClass("plugin.Greeter");
+        System.out.println(greeterClass.getName());
+        System.out.println(greeterClass.getClassLoader() == loader);
+        System.out.println(loader.loadClass("plugin.Greeter") == greeterClass);
+
+        Runnable greeter = (Runnable) greeterClass.newInstance();
+        greeter.run();
+        greeter.run();
+
+        try {
+            loader.loadClass("plugin.Missing");
+        } catch (ClassNotFoundException e) {
+            System.out.println(e.getMessage());
+        }
+
+        byte[] badMagic = copy(GreeterBytes.BYTES);
+        badMagic[0] = 0;
+        define("bad magic", badMagic, badMagic.length);
+
+        // the first constant is Methodref, its class index is out of range now
+        byte[] badIndex = copy(GreeterBytes.BYTES);
+        badIndex[11] = 0x7F;
+        define("bad constant pool index", badIndex, badIndex.length);
+
+        byte[] truncated = copy(GreeterBytes.BYTES);
+        define("truncated", truncated, 40);
+
+        define("valid", copy(GreeterBytes.BYTES), GreeterBytes.BYTES.length);
+    }
+
+    private static void define(String label, byte[] bytes, int length) {
+        System.out.println(label);
+
+        try {
+            Class<?> c = new ByteLoader(bytes).define(length);
+            System.out.println(c.getName());
+        } catch (ClassFormatError e) {
+            System.out.println(e.getClass().getName());
+            System.out.println(e.getMessage());
+        }
+    }
+
+    private static byte[] copy(byte[] bytes) {
+        byte[] result = new byte[bytes.length];
+
+        for (int i = 0; i < bytes.length; i++) {
+            result[i] = bytes[i];
+        }
+
+        return result;
+    }
+}
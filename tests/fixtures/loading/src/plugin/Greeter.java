@@ -0,0 +1,13 @@
+package plugin;
+
+public class Greeter implements Runnable {
+
+    static int greetings = 41;
+
+    @Override
+    public void run() {
+        greetings++;
+        System.out.println("hello from defined class");
+        System.out.println(greetings);
+    }
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{expected_output, run_fixture};
+
+// every fixture prints results of the instructions it covers, the expected output is produced
+// by HotSpot for the same classes
+fn assert_fixture_output(main_class: &str) {
+    assert_eq!(
+        run_fixture("interpreter", main_class, &[]),
+        expected_output("interpreter", main_class)
+    );
+}
+
+#[test]
+fn int_arithmetic() {
+    assert_fixture_output("interpreter.Arithmetic");
+}
+
+#[test]
+fn long_float_and_double_arithmetic() {
+    assert_fixture_output("interpreter.LongsAndDoubles");
+}
+
+#[test]
+fn numeric_conversions() {
+    assert_fixture_output("interpreter.Conversions");
+}
+
+#[test]
+fn table_and_lookup_switches() {
+    assert_fixture_output("interpreter.Switches");
+}
+
+#[test]
+fn wide_locals_and_stack_duplicates() {
+    assert_fixture_output("interpreter.WideAndDup");
+}